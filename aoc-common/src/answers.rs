@@ -0,0 +1,62 @@
+// Checking the solutions against the answers for the real puzzle inputs, so refactors can't
+// silently change them.
+//
+// Like the inputs, the answers aren't checked in. They live at answers/dayNN.toml in the root of
+// the repo, with an entry for each part you want checked:
+//
+//     part1 = 54388
+//     part2 = "some text"
+//
+// Each day has an ignored test which calls [check], so run them with `cargo test -- --ignored`.
+
+use crate::input::{self, find_in_ancestors};
+use crate::solution::Solution;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub fn default_file_name(day: u8) -> PathBuf {
+    Path::new("answers").join(format!("day{:02}.toml", day))
+}
+
+// Panics if any of the answers in answers/dayNN.toml don't match. Days without an answers file are
+// skipped, so you only need to fill in the days you have inputs for.
+pub fn check<S: Solution>(day: u8) {
+    let Ok(path) = find_in_ancestors(&default_file_name(day)) else {
+        eprintln!(
+            "skipping day {}: no {}",
+            day,
+            default_file_name(day).display()
+        );
+        return;
+    };
+
+    let answers: toml::Table = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Couldn't read {}: {}", path.display(), e))
+        .parse()
+        .unwrap_or_else(|e| panic!("Couldn't parse {}: {}", path.display(), e));
+    let expected = |part: &str| -> Option<String> {
+        match answers.get(part)? {
+            toml::Value::Integer(x) => Some(x.to_string()),
+            toml::Value::String(s) => Some(s.clone()),
+            other => panic!(
+                "{} in {} should be an integer or a string, not {}",
+                part,
+                path.display(),
+                other
+            ),
+        }
+    };
+
+    let s = input::read_input(day, None).unwrap_or_else(|e| panic!("{}", e));
+    let input = S::parse(&s).unwrap_or_else(|e| panic!("{}", e.render()));
+
+    // Only solve the parts we've got answers for - some of them are slow.
+    if let Some(expected) = expected("part1") {
+        let answer = S::part1(&input).to_string();
+        assert_eq!(answer, expected, "Wrong answer for day {} part 1", day);
+    }
+    if let Some(expected) = expected("part2") {
+        let answer = S::part2(&input).to_string();
+        assert_eq!(answer, expected, "Wrong answer for day {} part 2", day);
+    }
+}
@@ -0,0 +1,190 @@
+// Random puzzle inputs, for fuzzing the parsers and for trying the solutions out on bigger (or
+// smaller) inputs than the real ones.
+//
+// Each day implements [Generate]. Every generator takes a size, and some of them take a density
+// too - what these mean is up to the day, e.g. the number of bricks on day 22, or how many of the
+// springs are unknown on day 12. Inputs are generated from a seeded [StdRng], so they can be
+// recreated later.
+//
+// As well as being syntactically valid, the inputs are built to have whatever structure the
+// solutions rely on (a loop on day 10, exactly one smudge on day 13, a 3-edge cut on day 25...),
+// so they can be solved as well as parsed.
+
+use crate::grid::{Dir, Grid, Position};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+pub trait Generate {
+    // What the size counts, e.g. "bricks".
+    const SIZE: &'static str;
+    // Roughly the size of the real inputs.
+    const DEFAULT_SIZE: usize;
+    // The sizes [generate] can make an input that can be solved for. The runner won't ask for
+    // anything outside them.
+    const MIN_SIZE: usize = 1;
+    const MAX_SIZE: usize = usize::MAX;
+    // What the density is a probability of, if the day uses it.
+    const DENSITY: Option<&'static str> = None;
+    const DEFAULT_DENSITY: f64 = 0.5;
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String;
+}
+
+// The outline of a random tree, which makes a random loop that never touches itself.
+//
+// The tree's nodes are every other cell of the [filled] grid, in both directions, and the cells
+// between two nodes are filled in if there's an edge between them. Trees have no holes, and two
+// filled cells never only touch at a corner, so the outline is a single simple loop. It runs along
+// the edges of the cells, so its corners are the points between cells: (0, 0) is the top-left
+// corner of the grid, and (height, width) is the bottom-right.
+pub struct Outline {
+    pub filled: Grid<bool>,
+    // The corners of the loop, clockwise from (0, 0).
+    pub corners: Vec<Position>,
+}
+
+impl Outline {
+    // The outline of a random spanning tree of a [nodes] x [nodes] grid of nodes, so the loop fits
+    // in a grid of (2 * nodes - 1) x (2 * nodes - 1) cells.
+    pub fn random(rng: &mut StdRng, nodes: usize) -> Self {
+        assert!(nodes > 0, "Need at least one node for an outline");
+        let size = 2 * nodes - 1;
+        let mut filled = Grid::new(size, size, false);
+
+        // Randomised Prim's algorithm: grow the tree from the top-left node, one random edge at a
+        // time. This branches a lot more than a depth-first search, so the loop has lots of corners.
+        filled[Position(0, 0)] = true;
+        let mut frontier: Vec<(Position, Dir)> =
+            vec![(Position(0, 0), Dir::Right), (Position(0, 0), Dir::Down)];
+        while !frontier.is_empty() {
+            let (node, dir) = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+            let Some(edge) = filled.step(node, dir) else {
+                continue;
+            };
+            let Some(next) = filled.step(edge, dir) else {
+                continue;
+            };
+            if filled[next] {
+                continue;
+            }
+            filled[edge] = true;
+            filled[next] = true;
+            frontier.extend(Dir::ALL.map(|dir| (next, dir)));
+        }
+
+        let corners = trace_outline(&filled);
+        Outline { filled, corners }
+    }
+
+    // Whether the point between cells (row, col) is strictly inside the loop, for points that
+    // aren't on it. A point that isn't on the loop is surrounded by 4 cells that are either all
+    // filled or all not, so it's enough to look at one of them.
+    pub fn is_inside(&self, row: usize, col: usize) -> bool {
+        let row = row.min(self.filled.height() - 1);
+        let col = col.min(self.filled.width() - 1);
+        self.filled[Position(row, col)]
+    }
+}
+
+fn trace_outline(filled: &Grid<bool>) -> Vec<Position> {
+    // Cells outside the grid are empty.
+    let is_filled = |row: usize, col: usize, dr: isize, dc: isize| -> bool {
+        match (row.checked_add_signed(dr), col.checked_add_signed(dc)) {
+            (Some(row), Some(col)) => filled.get(Position(row, col)).copied().unwrap_or(false),
+            _ => false,
+        }
+    };
+    // Is there an edge of the loop leaving the point (row, col) in this direction? There is if the
+    // cells on either side of that edge are different.
+    let has_edge = |Position(row, col): Position, dir: Dir| -> bool {
+        match dir {
+            Dir::Right => is_filled(row, col, -1, 0) != is_filled(row, col, 0, 0),
+            Dir::Left => is_filled(row, col, -1, -1) != is_filled(row, col, 0, -1),
+            Dir::Down => is_filled(row, col, 0, -1) != is_filled(row, col, 0, 0),
+            Dir::Up => is_filled(row, col, -1, -1) != is_filled(row, col, -1, 0),
+        }
+    };
+    let step = |Position(row, col): Position, dir: Dir| -> Position {
+        match dir {
+            Dir::Up => Position(row - 1, col),
+            Dir::Down => Position(row + 1, col),
+            Dir::Left => Position(row, col - 1),
+            Dir::Right => Position(row, col + 1),
+        }
+    };
+
+    // The top-left cell is always filled, so start by going right along its top edge. Every point
+    // on the loop has exactly two edges, so there's only ever one way to carry on.
+    let start = Position(0, 0);
+    let mut corners = vec![start];
+    let mut position = step(start, Dir::Right);
+    let mut dir = Dir::Right;
+    while position != start {
+        let next_dir = Dir::ALL
+            .into_iter()
+            .find(|d| *d != dir.flip() && has_edge(position, *d))
+            .expect("Every point on the outline should have two edges");
+        if next_dir != dir {
+            corners.push(position);
+            dir = next_dir;
+        }
+        position = step(position, dir);
+    }
+    corners
+}
+
+// Lowercase names of [len] letters, all different from each other and anything in [taken].
+pub fn distinct_names(rng: &mut StdRng, n: usize, len: usize, taken: &[&str]) -> Vec<String> {
+    assert!(
+        (n + taken.len()) as f64 <= 26f64.powi(len as i32),
+        "Not enough names of length {} to go round",
+        len
+    );
+    let mut names: Vec<String> = vec![];
+    let mut seen: HashSet<String> = taken.iter().map(|s| s.to_string()).collect();
+    while names.len() < n {
+        let name: String = (0..len).map(|_| rng.gen_range('a'..='z')).collect();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names.shuffle(rng);
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn outlines_are_closed_rectilinear_loops() {
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let outline = Outline::random(&mut rng, 1 + seed as usize % 6);
+            let corners = &outline.corners;
+
+            // Consecutive corners line up, and the loop turns at every corner.
+            for i in 0..corners.len() {
+                let a = corners[i];
+                let b = corners[(i + 1) % corners.len()];
+                let c = corners[(i + 2) % corners.len()];
+                assert!((a.0 == b.0) != (a.1 == b.1));
+                assert!((a.0 == b.0) != (b.0 == c.0));
+            }
+            // Clockwise, so the area from the shoelace formula is positive, and it's the area of
+            // the filled cells.
+            let twice_area: i64 = (0..corners.len())
+                .map(|i| {
+                    let Position(r1, c1) = corners[i];
+                    let Position(r2, c2) = corners[(i + 1) % corners.len()];
+                    c1 as i64 * r2 as i64 - c2 as i64 * r1 as i64
+                })
+                .sum();
+            let filled = outline.filled.iter().filter(|(_p, f)| **f).count() as i64;
+            assert_eq!(twice_area, 2 * filled);
+        }
+    }
+}
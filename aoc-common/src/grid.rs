@@ -0,0 +1,321 @@
+// A rectangular grid of cells, plus the directions and positions used to move around it.
+//
+// Positions are (row, column), with row 0 at the top. Everything that moves around the grid goes
+// through [Grid::step], so there's only one place that does bounds checking.
+
+use crate::parse::{char_token, end_of, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+use Dir::*;
+
+impl Dir {
+    // In clockwise order, starting from Up.
+    pub const ALL: [Dir; 4] = [Up, Right, Down, Left];
+
+    pub fn flip(self) -> Self {
+        match self {
+            Up => Down,
+            Down => Up,
+            Left => Right,
+            Right => Left,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().flip()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position(pub usize, pub usize);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    // Stored row by row.
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; height * width],
+            height,
+            width,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "All rows of a grid must be the same length"
+        );
+
+        Grid {
+            cells: rows.into_iter().flatten().collect(),
+            height,
+            width,
+        }
+    }
+
+    // Parse a map of characters, one row per line. Surrounding whitespace is ignored, but there has
+    // to be at least one row. [f] returns None for characters that shouldn't be in the map, and
+    // [expected] describes the ones that should.
+    pub fn parse(
+        s: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = vec![];
+
+        for line in s.trim().lines() {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| f(c).ok_or_else(|| ParseError::new(char_token(line, i), expected)))
+                .collect::<Result<Vec<T>, _>>()?;
+
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::new(
+                        line,
+                        format!("a row of {} characters", first.len()),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+
+        if rows.is_empty() {
+            return Err(ParseError::new(end_of(s), "at least one row"));
+        }
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, p: Position) -> bool {
+        p.0 < self.height && p.1 < self.width
+    }
+
+    pub fn get(&self, p: Position) -> Option<&T> {
+        if self.contains(p) {
+            Some(&self.cells[p.0 * self.width + p.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Position) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.0 * self.width + p.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|i| self.row(i))
+    }
+
+    // Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| Position(i, j)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, mut f: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter().find(|(_p, t)| f(t)).map(|(p, _t)| p)
+    }
+
+    // The position one step from [p] in [dir], or None if that would leave the grid.
+    pub fn step(&self, p: Position, dir: Dir) -> Option<Position> {
+        let Position(i, j) = p;
+        let stepped = match dir {
+            Up => Position(i.checked_sub(1)?, j),
+            Down => Position(i + 1, j),
+            Left => Position(i, j.checked_sub(1)?),
+            Right => Position(i, j + 1),
+        };
+        if self.contains(stepped) {
+            Some(stepped)
+        } else {
+            None
+        }
+    }
+
+    // The (up to) 4 positions sharing an edge with [p].
+    pub fn neighbours(&self, p: Position) -> impl Iterator<Item = Position> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(p, dir))
+    }
+
+    // The (up to) 8 positions sharing an edge or a corner with [p].
+    pub fn neighbours8(&self, p: Position) -> impl Iterator<Item = Position> + '_ {
+        let Position(i, j) = p;
+        (i.saturating_sub(1)..=i + 1)
+            .flat_map(move |x| (j.saturating_sub(1)..=j + 1).map(move |y| Position(x, y)))
+            .filter(move |q| *q != p && self.contains(*q))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+
+    // Swap rows and columns, so (i, j) ends up at (j, i).
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let rows = (0..self.width)
+            .map(|j| {
+                (0..self.height)
+                    .map(|i| self[Position(i, j)].clone())
+                    .collect()
+            })
+            .collect();
+        Grid::from_rows(rows)
+    }
+
+    // Rotate a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let rows = (0..self.width)
+            .map(|j| {
+                (0..self.height)
+                    .rev()
+                    .map(|i| self[Position(i, j)].clone())
+                    .collect()
+            })
+            .collect();
+        Grid::from_rows(rows)
+    }
+
+    pub fn rotate_anticlockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let rows = (0..self.width)
+            .rev()
+            .map(|j| {
+                (0..self.height)
+                    .map(|i| self[Position(i, j)].clone())
+                    .collect()
+            })
+            .collect();
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Position) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", p))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, p: Position) -> &mut T {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", p))
+    }
+}
+
+// Prints the grid back out in the same format it was parsed from.
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().map(|c| (*c).into()).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("ab\ncd\nef\n", "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn steps_inside_the_grid() {
+        let grid = example();
+        assert_eq!(grid.step(Position(0, 0), Right), Some(Position(0, 1)));
+        assert_eq!(grid.step(Position(0, 0), Up), None);
+        assert_eq!(grid.step(Position(2, 1), Down), None);
+        assert_eq!(grid.neighbours(Position(1, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Position(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn rotates() {
+        let grid = example();
+        assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "bdf\nace\n");
+        assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let e = Grid::parse("ab\nc\n", "a letter", Some).unwrap_err();
+        assert_eq!(e.expected(), "a row of 2 characters");
+        assert_eq!(e.token(), "c");
+    }
+
+    #[test]
+    fn rejects_empty_grids() {
+        for s in ["", "\n\n", "  \n"] {
+            let e = Grid::parse(s, "a letter", Some).unwrap_err().locate(s);
+            assert_eq!(e.expected(), "at least one row");
+            assert_eq!(e.token(), "");
+        }
+    }
+}
@@ -0,0 +1,137 @@
+// Loading puzzle inputs at runtime.
+//
+// Inputs aren't checked in. By convention they live at inputs/dayNN.txt in the root of the
+// repo, but any file (or stdin, with "-") can be given instead.
+
+use crate::report::Format;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+#[derive(Debug)]
+pub enum InputError {
+    // No input was given, and we couldn't find one in any of these places.
+    NotFound { day: u8, searched: Vec<PathBuf> },
+    Unreadable { path: PathBuf, error: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, searched } => {
+                write!(f, "couldn't find an input for day {}, looked for:", day)?;
+                for path in searched {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                write!(f, "\npass a path to the input, or - to read it from stdin")
+            }
+            InputError::Unreadable { path, error } => {
+                write!(f, "couldn't read {}: {}", path.display(), error)
+            }
+            InputError::Stdin(error) => write!(f, "couldn't read the input from stdin: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn default_file_name(day: u8) -> PathBuf {
+    Path::new("inputs").join(format!("day{:02}.txt", day))
+}
+
+// Look for [file_name] in the current directory and then each of its parents, so this works from
+// inside a day's directory as well as from the root of the workspace. On failure, returns every
+// path that was tried.
+pub(crate) fn find_in_ancestors(file_name: &Path) -> Result<PathBuf, Vec<PathBuf>> {
+    let current_dir = env::current_dir().unwrap_or_default();
+
+    let searched: Vec<PathBuf> = current_dir
+        .ancestors()
+        .map(|dir| dir.join(file_name))
+        .collect();
+
+    match searched.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(searched),
+    }
+}
+
+fn find_default_input(day: u8) -> Result<PathBuf, InputError> {
+    find_in_ancestors(&default_file_name(day))
+        .map_err(|searched| InputError::NotFound { day, searched })
+}
+
+// [arg] is what the user passed: a path, "-" for stdin, or nothing to use the conventional location.
+pub fn read_input(day: u8, arg: Option<&str>) -> Result<String, InputError> {
+    let path = match arg {
+        Some("-") => {
+            let mut s = String::new();
+            io::stdin()
+                .read_to_string(&mut s)
+                .map_err(InputError::Stdin)?;
+            return Ok(s);
+        }
+        Some(path) => PathBuf::from(path),
+        None => find_default_input(day)?,
+    };
+
+    fs::read_to_string(&path).map_err(|error| InputError::Unreadable { path, error })
+}
+
+// What was passed to one of the days' own binaries.
+pub struct Args {
+    pub input: String,
+    pub format: Format,
+    // The number of -v flags.
+    pub verbosity: u8,
+}
+
+// For the days' own binaries: read the input named on the command line, or exit with an error
+// message if we can't.
+pub fn from_args(day: u8) -> Args {
+    let usage = || -> ! {
+        eprintln!("usage: day{} [-v|-vv] [--format text|json] [INPUT]", day);
+        eprintln!("INPUT is a path to the puzzle input, or - for stdin.");
+        eprintln!("Defaults to {}.", default_file_name(day).display());
+        process::exit(2);
+    };
+
+    let mut format = Format::Text;
+    let mut verbosity = 0;
+    let mut path = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--format" {
+            let Some(value) = args.next() else { usage() };
+            format = value.parse().unwrap_or_else(|e| {
+                eprintln!("error: {}", e);
+                usage()
+            });
+        } else if let Some(vs) = arg.strip_prefix('-').filter(|vs| !vs.is_empty()) {
+            if vs.chars().any(|c| c != 'v') {
+                usage();
+            }
+            verbosity += vs.len() as u8;
+        } else if path.is_none() {
+            path = Some(arg);
+        } else {
+            usage();
+        }
+    }
+
+    match read_input(day, path.as_deref()) {
+        Ok(input) => Args {
+            input,
+            format,
+            verbosity,
+        },
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
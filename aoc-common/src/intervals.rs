@@ -0,0 +1,165 @@
+// Sets of numbers made of a few long runs, like the seed ranges on day 5, where listing every number
+// would take far too long.
+
+use std::ops::Range;
+
+// Always kept normalised: the ranges are sorted, none of them are empty, and no two of them overlap
+// or touch. So two sets with the same numbers in are always equal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<Range<usize>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<usize>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // How many numbers are in the set.
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(|r| r.len()).sum()
+    }
+
+    pub fn contains(&self, v: usize) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= v);
+        self.ranges.get(i).is_some_and(|r| r.contains(&v))
+    }
+
+    pub fn min(&self) -> Option<usize> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        // Both lists are sorted, so walk along them together, always moving on from whichever
+        // range finishes first.
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    // Everything in this set but not in [other].
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            // Skip the ranges of [other] which finish before this one starts - they can't finish
+            // before any later one starts either.
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < range.end {
+                if start < other.ranges[k].start {
+                    ranges.push(start..other.ranges[k].start);
+                }
+                start = start.max(other.ranges[k].end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+// Collecting ranges into a set normalises them.
+impl FromIterator<Range<usize>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<usize>>>(iter: I) -> Self {
+        let mut sorted: Vec<Range<usize>> = iter.into_iter().filter(|r| !r.is_empty()).collect();
+        sorted.sort_by_key(|r| r.start);
+
+        let mut ranges: Vec<Range<usize>> = vec![];
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl From<Range<usize>> for IntervalSet {
+    fn from(range: Range<usize>) -> Self {
+        [range].into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<usize>]) -> IntervalSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn normalises() {
+        let s = set(&[10..12, 0..3, 5..5, 2..4, 4..6, 11..15]);
+        assert_eq!(s.ranges(), &[0..6, 10..15]);
+        assert_eq!(s.len(), 11);
+        assert_eq!(s.min(), Some(0));
+        assert!(s.contains(5) && s.contains(10) && s.contains(14));
+        assert!(!s.contains(6) && !s.contains(15));
+        assert!(IntervalSet::from(3..3).is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30, 40..50]);
+        let b = set(&[5..25, 28..45, 60..70]);
+        assert_eq!(a.union(&b), set(&[0..50, 60..70]));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25, 28..30, 40..45]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..28, 45..50]));
+        assert_eq!(b.difference(&a), set(&[10..20, 30..40, 60..70]));
+        assert_eq!(a.difference(&a), IntervalSet::new());
+        assert_eq!(a.intersection(&IntervalSet::new()), IntervalSet::new());
+
+        // Check them against plain sets of numbers, for some awkward overlaps.
+        let numbers =
+            |s: &IntervalSet| -> Vec<usize> { (0..80).filter(|v| s.contains(*v)).collect() };
+        let c = set(&[0..1, 9..21, 29..41, 49..61, 65..66]);
+        for (x, y) in [(&a, &c), (&c, &a), (&b, &c), (&c, &b)] {
+            let (xs, ys) = (numbers(x), numbers(y));
+            let filter = |f: &dyn Fn(&usize) -> bool| (0..80).filter(f).collect::<Vec<_>>();
+            assert_eq!(
+                numbers(&x.union(y)),
+                filter(&|v| xs.contains(v) || ys.contains(v))
+            );
+            assert_eq!(
+                numbers(&x.intersection(y)),
+                filter(&|v| xs.contains(v) && ys.contains(v))
+            );
+            assert_eq!(
+                numbers(&x.difference(y)),
+                filter(&|v| xs.contains(v) && !ys.contains(v))
+            );
+        }
+    }
+}
@@ -0,0 +1,13 @@
+// Code shared between the days and the runner.
+
+pub mod answers;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod intervals;
+pub mod logging;
+pub mod maths;
+pub mod maze;
+pub mod parse;
+pub mod report;
+pub mod solution;
@@ -0,0 +1,27 @@
+// Diagnostics from inside the solutions go through [tracing], so normal runs only print the answers.
+//
+// Each day logs under its crate name as the target (day3, day14, ...), so one day can be turned up
+// on its own. -v shows debug messages and -vv shows trace messages too, for every day. For finer
+// control, RUST_LOG takes precedence over the flags, e.g. RUST_LOG=day14=trace.
+//
+// Everything is logged to stderr, so it doesn't get mixed up with the answers - or the JSON.
+
+use std::io::{self, IsTerminal};
+use tracing_subscriber::EnvFilter;
+
+// [verbosity] is the number of -v flags.
+pub fn init(verbosity: u8) {
+    let level = match verbosity {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level));
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
@@ -0,0 +1,39 @@
+// Bits of number theory which more than one day needs.
+
+use num_bigint::BigInt;
+use num_integer::{ExtendedGcd, Integer};
+use num_traits::Zero;
+
+// Solves x = a (mod m) and x = b (mod n) together, giving x = c (mod lcm(m, n)) - or nothing, if no
+// x works for both. This is the general Chinese Remainder Theorem, where m and n don't have to be
+// coprime.
+pub fn crt(a: &BigInt, m: &BigInt, b: &BigInt, n: &BigInt) -> Option<(BigInt, BigInt)> {
+    // m * x + n * y = g
+    let ExtendedGcd { gcd: g, x, .. } = m.extended_gcd(n);
+    let difference = b - a;
+    if !(&difference % &g).is_zero() {
+        return None;
+    }
+
+    // We want a + m * k = b (mod n), so m * k = difference (mod n). Dividing through by g,
+    // (m / g) * k = difference / g (mod n / g), and x is the inverse of m / g mod n / g.
+    let lcm = m / &g * n;
+    let k = (&difference / &g * x).mod_floor(&(n / &g));
+    Some(((a + m * k).mod_floor(&lcm), lcm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crt_handles_moduli_with_common_factors() {
+        let crt = |a: i64, m: i64, b: i64, n: i64| {
+            crt(&a.into(), &m.into(), &b.into(), &n.into())
+                .map(|(c, l)| (i64::try_from(c).unwrap(), i64::try_from(l).unwrap()))
+        };
+        assert_eq!(crt(2, 3, 3, 5), Some((8, 15)));
+        assert_eq!(crt(1, 4, 3, 6), Some((9, 12)));
+        assert_eq!(crt(0, 4, 1, 6), None);
+    }
+}
@@ -0,0 +1,252 @@
+// Compressing a maze on a grid into a much smaller graph, for searches that would be too slow a cell
+// at a time.
+//
+// Most cells of a maze are in corridors, with exactly two ways in or out, so there's no choice to
+// make there. The vertices of the graph are the rest - junctions, dead ends, and anything else that
+// needs to stay, like the start and end - and the edges are the corridors between them, weighted by
+// their length. Each edge remembers the cells it goes through, so a path through the graph can be
+// turned back into a path through the grid.
+//
+// Day 23 uses this for its trails. It works for any grid where it's clear which cells can be walked
+// on, like the pipes on day 10 or the garden plots on day 21.
+
+use crate::grid::{Dir, Grid, Position};
+use std::collections::HashMap;
+
+// How a cell of the maze can be walked through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Wall,
+    Open,
+    // Like the icy slopes on day 23: anything that steps on to it has to step off it in this
+    // direction.
+    OneWay(Dir),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edge {
+    // The index of the vertex the edge goes to.
+    pub to: usize,
+    // The number of steps along it.
+    pub length: usize,
+    // The cells along the edge, not including the one it starts from but including the one it ends
+    // at, so there are [length] of them.
+    pub cells: Vec<Position>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MazeGraph {
+    // Where each vertex is, in order.
+    pub vertices: Vec<Position>,
+    // The edges out of each vertex. Two vertices can be joined by more than one corridor, so there
+    // can be more than one edge between them. Corridors which loop back to where they started
+    // aren't any use for getting anywhere, so they're left out.
+    pub edges: Vec<Vec<Edge>>,
+    index: HashMap<Position, usize>,
+}
+
+impl MazeGraph {
+    // The index of the vertex at [position], if there is one.
+    pub fn vertex(&self, position: Position) -> Option<usize> {
+        self.index.get(&position).copied()
+    }
+
+    // Turns a path through the graph, as a list of vertices, back into the cells it goes through,
+    // including the first one. Where there's more than one edge between two vertices, it takes the
+    // longest. None if two of the vertices aren't joined.
+    pub fn cells(&self, path: &[usize]) -> Option<Vec<Position>> {
+        let mut cells = vec![self.vertices[*path.first()?]];
+        for w in path.windows(2) {
+            let edge = self.edges[w[0]]
+                .iter()
+                .filter(|edge| edge.to == w[1])
+                .max_by_key(|edge| edge.length)?;
+            cells.extend(&edge.cells);
+        }
+        Some(cells)
+    }
+}
+
+// Where you can go next from [p], which has to be walkable.
+fn exits<T>(grid: &Grid<T>, cell: &impl Fn(&T) -> Cell, p: Position) -> Vec<Position> {
+    let dirs = match cell(&grid[p]) {
+        Cell::OneWay(dir) => vec![dir],
+        _ => Dir::ALL.to_vec(),
+    };
+    dirs.into_iter()
+        .filter_map(|dir| grid.step(p, dir))
+        .filter(|q| cell(&grid[*q]) != Cell::Wall)
+        .collect()
+}
+
+// Compresses the maze, keeping a vertex at every cell in [keep] as well as the junctions and dead
+// ends. A cell counts as a junction if more than two of its neighbours can be walked on, even if the
+// slopes mean it can't actually go all of those ways.
+pub fn compress<T>(grid: &Grid<T>, keep: &[Position], cell: impl Fn(&T) -> Cell) -> MazeGraph {
+    let walkable = |p: Position| cell(&grid[p]) != Cell::Wall;
+    let degree = |p: Position| grid.neighbours(p).filter(|q| walkable(*q)).count();
+
+    let vertices: Vec<Position> = grid
+        .positions()
+        .filter(|p| walkable(*p) && (degree(*p) != 2 || keep.contains(p)))
+        .collect();
+    let index: HashMap<Position, usize> =
+        vertices.iter().enumerate().map(|(i, p)| (*p, i)).collect();
+
+    let edges = vertices
+        .iter()
+        .map(|start| {
+            // Follow each corridor out of the vertex until it gets to another one. Every cell in
+            // the corridor has exactly two walkable neighbours, so there's only one way to go on -
+            // unless a slope's in the way, in which case the corridor's a dead end this way round.
+            let mut edges = vec![];
+            'corridors: for first in exits(grid, &cell, *start) {
+                let mut cells = vec![first];
+                let mut previous = *start;
+                let mut current = first;
+                while !index.contains_key(&current) {
+                    let next = grid
+                        .neighbours(current)
+                        .find(|q| *q != previous && walkable(*q))
+                        .expect("Corridors have two ends");
+                    if !exits(grid, &cell, current).contains(&next) {
+                        continue 'corridors;
+                    }
+                    cells.push(next);
+                    (previous, current) = (current, next);
+                }
+                if current != *start {
+                    edges.push(Edge {
+                        to: index[&current],
+                        length: cells.len(),
+                        cells,
+                    });
+                }
+            }
+            edges
+        })
+        .collect();
+
+    MazeGraph {
+        vertices,
+        edges,
+        index,
+    }
+}
+
+// For mazes without any one-way cells.
+pub fn compress_passable<T>(
+    grid: &Grid<T>,
+    keep: &[Position],
+    passable: impl Fn(&T) -> bool,
+) -> MazeGraph {
+    compress(grid, keep, |t| match passable(t) {
+        true => Cell::Open,
+        false => Cell::Wall,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        Grid::parse(s, "anything", Some).unwrap()
+    }
+
+    #[test]
+    fn compresses_corridors() {
+        // A loop with a tail: the junction at (1, 1) has two ways round the loop, which come back
+        // to it, and the tail leads off to a dead end.
+        let g = grid(
+            "\
+#####
+#...#
+#.#.#
+#...#
+#.###
+",
+        );
+        let maze = compress_passable(&g, &[Position(4, 1)], |c| *c != '#');
+        assert_eq!(maze.vertices, vec![Position(3, 1), Position(4, 1)]);
+        let junction = maze.vertex(Position(3, 1)).unwrap();
+        let end = maze.vertex(Position(4, 1)).unwrap();
+
+        // The loop round the top doesn't go anywhere, so it isn't an edge.
+        assert_eq!(
+            maze.edges[junction],
+            vec![Edge {
+                to: end,
+                length: 1,
+                cells: vec![Position(4, 1)],
+            }]
+        );
+        assert_eq!(maze.edges[end].len(), 1);
+        assert_eq!(
+            maze.cells(&[end, junction]),
+            Some(vec![Position(4, 1), Position(3, 1)])
+        );
+    }
+
+    #[test]
+    fn keeps_both_corridors_between_two_junctions() {
+        let g = grid(
+            "\
+.....
+.#.#.
+.....
+",
+        );
+        let keep = [Position(0, 0), Position(2, 4)];
+        let maze = compress_passable(&g, &keep, |c| *c == '.');
+        let start = maze.vertex(Position(0, 0)).unwrap();
+        let middle = maze.vertex(Position(0, 2)).unwrap();
+        let lengths: Vec<usize> = maze.edges[start]
+            .iter()
+            .filter(|edge| edge.to == middle)
+            .map(|edge| edge.length)
+            .collect();
+        assert_eq!(lengths, vec![2]);
+        // Every edge's cells are a walk of single steps, ending at the vertex it goes to.
+        for (from, edges) in maze.edges.iter().enumerate() {
+            for edge in edges {
+                let mut previous = maze.vertices[from];
+                for cell in &edge.cells {
+                    assert_eq!(previous.0.abs_diff(cell.0) + previous.1.abs_diff(cell.1), 1);
+                    previous = *cell;
+                }
+                assert_eq!(previous, maze.vertices[edge.to]);
+                assert_eq!(edge.cells.len(), edge.length);
+            }
+        }
+    }
+
+    #[test]
+    fn slopes_only_go_one_way() {
+        let g = grid(
+            "\
+#.#
+#v#
+#.#
+#.#
+",
+        );
+        let cell = |c: &char| match c {
+            '#' => Cell::Wall,
+            'v' => Cell::OneWay(Dir::Down),
+            _ => Cell::Open,
+        };
+        let maze = compress(&g, &[], cell);
+        let top = maze.vertex(Position(0, 1)).unwrap();
+        let bottom = maze.vertex(Position(3, 1)).unwrap();
+        assert_eq!(maze.edges[top].len(), 1);
+        assert_eq!(maze.edges[top][0].to, bottom);
+        assert_eq!(maze.edges[top][0].length, 3);
+        assert!(maze.edges[bottom].is_empty());
+
+        // Without the slope, it goes both ways.
+        let maze = compress_passable(&g, &[], |c| *c != '#');
+        assert_eq!(maze.edges[bottom].len(), 1);
+        assert_eq!(maze.cells(&[bottom, top]).unwrap().len(), 4);
+    }
+}
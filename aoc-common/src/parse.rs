@@ -0,0 +1,226 @@
+// Errors (and a few helpers) for parsing puzzle inputs.
+//
+// A [ParseError] is created from the offending token, which must be a slice of the puzzle input -
+// that's how we work out where it was. Errors start off unlocated, and the top-level parse function
+// for each day calls [ParseError::locate] with the whole input to fill in the line and column. This
+// means the helper parsers don't need to pass the whole input around just in case they fail.
+
+use std::fmt;
+use std::process;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    token: String,
+    expected: String,
+    // The address of the token in memory, until we know which input it came from.
+    address: usize,
+    location: Option<Location>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    // Both 1-based, and the column counts characters rather than bytes.
+    pub line: usize,
+    pub column: usize,
+    pub line_text: String,
+}
+
+impl ParseError {
+    // [token] should be a slice of the input, so we can find it again in [ParseError::locate].
+    pub fn new(token: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            token: token.to_string(),
+            expected: expected.into(),
+            address: token.as_ptr() as usize,
+            location: None,
+        }
+    }
+
+    // Work out the line and column of the token, given the input it was a slice of. Errors that
+    // already have a location, or whose token isn't part of [input], are left alone.
+    pub fn locate(mut self, input: &str) -> Self {
+        if self.location.is_some() {
+            return self;
+        }
+
+        let start = input.as_ptr() as usize;
+        if self.address < start || self.address > start + input.len() {
+            return self;
+        }
+        let offset = self.address - start;
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = input[offset..]
+            .find('\n')
+            .map(|i| offset + i)
+            .unwrap_or(input.len());
+
+        self.location = Some(Location {
+            line: before.matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            line_text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        });
+        self
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    fn found(&self) -> String {
+        if self.token.is_empty() {
+            "nothing".to_string()
+        } else {
+            format!("{:?}", self.token)
+        }
+    }
+
+    // A longer, multi-line version of the error, quoting the line it happened on:
+    //
+    //     line 3, column 7: expected a number, found "7x"
+    //       |
+    //     3 | 32T3K 7x
+    //       |       ^^
+    pub fn render(&self) -> String {
+        let Some(location) = &self.location else {
+            return self.to_string();
+        };
+
+        let gutter = " ".repeat(location.line.to_string().len());
+        let underline = "^".repeat(self.token.chars().count().max(1));
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            gutter,
+            location.line,
+            location.line_text,
+            gutter,
+            " ".repeat(location.column - 1),
+            underline
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(location) = &self.location {
+            write!(f, "line {}, column {}: ", location.line, location.column)?;
+        }
+        write!(f, "expected {}, found {}", self.expected, self.found())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Parse a number, or anything else with a FromStr implementation.
+pub fn number<T: FromStr>(token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(token, "a number"))
+}
+
+// Like [str::split_once], but an error if the separator isn't there.
+pub fn split_once<'a>(s: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(separator)
+        .ok_or_else(|| ParseError::new(s, format!("{:?}", separator)))
+}
+
+// Like [str::strip_prefix], but an error if the prefix isn't there.
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        // Point at the start of the string, and only as much of it as should have matched.
+        let end = s
+            .char_indices()
+            .map(|(i, _c)| i)
+            .nth(prefix.chars().count())
+            .unwrap_or(s.len());
+        ParseError::new(&s[..end], format!("{:?}", prefix))
+    })
+}
+
+// Like [str::strip_suffix], but an error if the suffix isn't there.
+pub fn strip_suffix<'a>(s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+    s.strip_suffix(suffix).ok_or_else(|| {
+        // Point at the end of the string, where the suffix should have been.
+        let start = s
+            .char_indices()
+            .map(|(i, _c)| i)
+            .rev()
+            .nth(suffix.chars().count().saturating_sub(1))
+            .unwrap_or(0);
+        ParseError::new(&s[start..], format!("{:?}", suffix))
+    })
+}
+
+// The single-character slice of [s] starting at byte [i], so characters can be reported as tokens.
+pub fn char_token(s: &str, i: usize) -> &str {
+    let len = s[i..].chars().next().map(|c| c.len_utf8()).unwrap_or(0);
+    &s[i..i + len]
+}
+
+// An empty token at the end of [s], for reporting something that's missing altogether.
+pub fn end_of(s: &str) -> &str {
+    let s = s.trim_end();
+    &s[s.len()..]
+}
+
+// For the days' own binaries: unwrap the result of a part, or print the error and exit.
+pub fn or_exit<T>(result: Result<T, ParseError>) -> T {
+    match result {
+        Ok(t) => t,
+        Err(e) => {
+            eprintln!("error: couldn't parse the input: {}", e.render());
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_tokens() {
+        let input = "32T3K 765\nT55J5 6x4\n";
+        let line = input.lines().nth(1).unwrap();
+        let (_hand, bid) = split_once(line, " ").unwrap();
+
+        let e = number::<usize>(bid).unwrap_err().locate(input);
+        let location = e.location().unwrap();
+        assert_eq!((location.line, location.column), (2, 7));
+        assert_eq!(location.line_text, "T55J5 6x4");
+        assert_eq!(
+            e.render(),
+            "line 2, column 7: expected a number, found \"6x4\"\n  |\n2 | T55J5 6x4\n  |       ^^^"
+        );
+    }
+
+    #[test]
+    fn leaves_other_tokens_unlocated() {
+        let e = ParseError::new("elsewhere", "something").locate("an input");
+        assert_eq!(e.location(), None);
+        assert_eq!(e.to_string(), "expected something, found \"elsewhere\"");
+    }
+
+    #[test]
+    fn points_at_missing_prefixes_and_suffixes() {
+        assert_eq!(
+            strip_prefix("Tim: 7", "Time:").unwrap_err().token(),
+            "Tim: "
+        );
+        assert_eq!(strip_suffix("(a, b", ")").unwrap_err().token(), "b");
+        assert_eq!(end_of("abc\n\n"), "");
+    }
+}
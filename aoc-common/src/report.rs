@@ -0,0 +1,163 @@
+// Printing the answers, either for people or as JSON for other programs to consume.
+//
+// The JSON format is one object per line, for each part that was run:
+//
+//     {"day":1,"part":2,"answer":54388,"elapsed_ms":1.3}
+//
+// Integers that fit in an i64 are JSON numbers, and anything else is a string - big integers
+// included, since plenty of JSON parsers read every number as a double. Parts which weren't solved
+// in code have a null answer and elapsed_ms, and a "note" saying why, and parts which failed (or
+// whose input couldn't be read or parsed) have an "error" instead.
+
+use crate::input;
+use crate::logging;
+use crate::parse::or_exit;
+use crate::solution::{Answer, Solution};
+use serde_json::{json, Value};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format {:?}, expected text or json", s)),
+        }
+    }
+}
+
+pub fn format_duration(d: Duration) -> String {
+    format!("{:.1}ms", d.as_secs_f64() * 1000.0)
+}
+
+fn answer_to_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Integer(x) => json!(x),
+        Answer::BigInteger(x) => json!(x.to_string()),
+        Answer::Text(s) => json!(s),
+        Answer::Unsolved(_) | Answer::Failed(_) => Value::Null,
+    }
+}
+
+// One line of JSON output for a part that produced an [Answer].
+pub fn answer_json(day: u8, part: u8, answer: &Answer, elapsed: Duration) -> String {
+    let line = match answer {
+        // No point timing a part that didn't do anything.
+        Answer::Unsolved(note) => json!({
+            "day": day,
+            "part": part,
+            "answer": null,
+            "elapsed_ms": null,
+            "note": note,
+        }),
+        Answer::Failed(error) => return error_json(day, part, error),
+        _ => json!({
+            "day": day,
+            "part": part,
+            "answer": answer_to_json(answer),
+            "elapsed_ms": elapsed.as_secs_f64() * 1000.0,
+        }),
+    };
+    line.to_string()
+}
+
+// One line of JSON output for a part that didn't produce an answer at all.
+pub fn error_json(day: u8, part: u8, error: &str) -> String {
+    json!({
+        "day": day,
+        "part": part,
+        "answer": null,
+        "elapsed_ms": null,
+        "error": error,
+    })
+    .to_string()
+}
+
+pub fn answer_text(day: u8, part: u8, answer: &Answer, elapsed: Duration) -> String {
+    match answer {
+        Answer::Unsolved(_) | Answer::Failed(_) => {
+            format!("Day {}, part {}: {}", day, part, answer)
+        }
+        _ => format!(
+            "Day {}, part {}: {} ({})",
+            day,
+            part,
+            answer,
+            format_duration(elapsed)
+        ),
+    }
+}
+
+pub fn print_answer(format: Format, day: u8, part: u8, answer: &Answer, elapsed: Duration) {
+    match format {
+        Format::Text => println!("{}", answer_text(day, part, answer, elapsed)),
+        Format::Json => println!("{}", answer_json(day, part, answer, elapsed)),
+    }
+}
+
+// The whole of each day's binary: read the input named on the command line, solve both parts and
+// print the answers.
+pub fn main<S: Solution>(day: u8) {
+    let args = input::from_args(day);
+    logging::init(args.verbosity);
+    let format = args.format;
+    let input = or_exit(S::parse(&args.input));
+
+    let start = Instant::now();
+    let answer = S::part1(&input);
+    print_answer(format, day, 1, &answer, start.elapsed());
+
+    let start = Instant::now();
+    let answer = S::part2(&input);
+    print_answer(format, day, 2, &answer, start.elapsed());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    // Compare as values, since the order of the keys doesn't matter.
+    fn parse(line: String) -> Value {
+        serde_json::from_str(&line).unwrap()
+    }
+
+    #[test]
+    fn writes_json_lines() {
+        let elapsed = Duration::from_micros(1500);
+        assert_eq!(
+            parse(answer_json(1, 2, &Answer::Integer(54388), elapsed)),
+            json!({"day": 1, "part": 2, "answer": 54388, "elapsed_ms": 1.5})
+        );
+        assert_eq!(
+            parse(answer_json(8, 2, &BigInt::from(u64::MAX).into(), elapsed)),
+            json!({"day": 8, "part": 2, "answer": "18446744073709551615", "elapsed_ms": 1.5})
+        );
+        assert_eq!(
+            parse(answer_json(25, 2, &Answer::Unsolved("no puzzle"), elapsed)),
+            json!({"day": 25, "part": 2, "answer": null, "elapsed_ms": null, "note": "no puzzle"})
+        );
+        assert_eq!(
+            parse(answer_json(
+                24,
+                2,
+                &Answer::Failed("no rock".to_string()),
+                elapsed
+            )),
+            json!({"day": 24, "part": 2, "answer": null, "elapsed_ms": null, "error": "no rock"})
+        );
+        assert_eq!(
+            parse(error_json(3, 1, "no input")),
+            json!({"day": 3, "part": 1, "answer": null, "elapsed_ms": null, "error": "no input"})
+        );
+    }
+}
@@ -0,0 +1,104 @@
+// The interface every day implements, so the runner (and anything else) can drive them all in the
+// same way: parse the input once, and then solve each part from the parsed input.
+
+use crate::parse::ParseError;
+use num_bigint::BigInt;
+use std::fmt;
+
+pub trait Solution {
+    // The parsed puzzle input, shared between both parts.
+    type Input;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    // For answers too big for an i64.
+    BigInteger(BigInt),
+    Text(String),
+    // The part isn't solved in code. The note says how it got solved instead.
+    Unsolved(&'static str),
+    // The code couldn't solve this input, e.g. because it doesn't have the structure the solution
+    // relies on. Unlike [Unsolved], this is a problem rather than a gap.
+    Failed(String),
+}
+
+impl From<i64> for Answer {
+    fn from(x: i64) -> Self {
+        Answer::Integer(x)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(x: usize) -> Self {
+        i64::try_from(x)
+            .map(Answer::Integer)
+            .unwrap_or_else(|_| Answer::BigInteger(x.into()))
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(x: u64) -> Self {
+        i64::try_from(x)
+            .map(Answer::Integer)
+            .unwrap_or_else(|_| Answer::BigInteger(x.into()))
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(x: i128) -> Self {
+        i64::try_from(x)
+            .map(Answer::Integer)
+            .unwrap_or_else(|_| Answer::BigInteger(x.into()))
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(x: BigInt) -> Self {
+        // Keep small ones as plain integers, so they compare equal however they were worked out.
+        i64::try_from(&x)
+            .map(Answer::Integer)
+            .unwrap_or(Answer::BigInteger(x))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(x) => write!(f, "{}", x),
+            Answer::BigInteger(x) => write!(f, "{}", x),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved(note) => write!(f, "not solved in code ({})", note),
+            Answer::Failed(error) => write!(f, "failed: {}", error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_uses_big_integers_when_needed() {
+        assert_eq!(Answer::from(42usize), Answer::Integer(42));
+        assert_eq!(Answer::from(BigInt::from(42)), Answer::Integer(42));
+
+        let big = Answer::from(u64::MAX);
+        assert_eq!(big, Answer::BigInteger(BigInt::from(u64::MAX)));
+        assert_eq!(big.to_string(), "18446744073709551615");
+        assert_eq!(
+            Answer::from(-(1i128 << 70)).to_string(),
+            "-1180591620717411303424"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+// Benchmarks for every day, timing the parsing and each part separately against the real puzzle
+// inputs in inputs/dayNN.txt. Days without an input are skipped.
+//
+//     cargo bench --bench days              # every day
+//     cargo bench --bench days -- day05     # just one day
+//
+// Once the benchmarks are done, this prints a summary of the mean times for everything that was
+// run, slowest day first, so it's easy to see which days dominate the total runtime.
+
+use aoc_common::input::read_input;
+use aoc_common::solution::Solution;
+use criterion::{black_box, Criterion};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+// Parts that don't produce an answer in code, so there's nothing worth timing. There's no part 2 on
+// day 25.
+const SKIPPED: [(u8, &str); 1] = [(25, "part2")];
+
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8) {
+    let Ok(s) = read_input(day, None) else {
+        eprintln!("Skipping day {}: no input", day);
+        return;
+    };
+    let input = match S::parse(&s) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Skipping day {}: couldn't parse the input: {}", day, e);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day{:02}", day));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&s))));
+    if !SKIPPED.contains(&(day, "part1")) {
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    }
+    if !SKIPPED.contains(&(day, "part2")) {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+    }
+    group.finish();
+}
+
+macro_rules! bench_days {
+    ($c:expr, $($day:expr => $solution:ty),* $(,)?) => {
+        $(bench_day::<$solution>($c, $day);)*
+    };
+}
+
+// Where Criterion keeps its results: target/criterion, in the root of the workspace.
+fn criterion_dir() -> Option<PathBuf> {
+    if let Some(target) = env::var_os("CARGO_TARGET_DIR") {
+        return Some(PathBuf::from(target).join("criterion"));
+    }
+    let current_dir = env::current_dir().ok()?;
+    current_dir
+        .ancestors()
+        .map(|dir| dir.join("target").join("criterion"))
+        .find(|dir| dir.is_dir())
+}
+
+// The mean time of a benchmark, if it was run since [since]. Older results are from previous runs,
+// e.g. of days that were filtered out this time.
+fn mean_time(dir: &Path, day: u8, stage: &str, since: SystemTime) -> Option<Duration> {
+    let path = dir
+        .join(format!("day{:02}", day))
+        .join(stage)
+        .join("new")
+        .join("estimates.json");
+
+    if fs::metadata(&path).ok()?.modified().ok()? < since {
+        return None;
+    }
+    let estimates: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&path).ok()?).ok()?;
+    let nanos = estimates["mean"]["point_estimate"].as_f64()?;
+    Some(Duration::from_secs_f64(nanos / 1e9))
+}
+
+fn format_time(t: Option<Duration>) -> String {
+    let Some(t) = t else {
+        return "-".to_string();
+    };
+    let nanos = t.as_secs_f64() * 1e9;
+    if nanos < 1e3 {
+        format!("{:.1}ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+fn print_summary(since: SystemTime) {
+    let Some(dir) = criterion_dir() else {
+        return;
+    };
+
+    let mut rows: Vec<(u8, Vec<Option<Duration>>, Duration)> = (1..=25)
+        .map(|day| {
+            let times: Vec<Option<Duration>> = STAGES
+                .iter()
+                .map(|stage| mean_time(&dir, day, stage, since))
+                .collect();
+            let total = times.iter().flatten().sum();
+            (day, times, total)
+        })
+        .filter(|(_day, times, _total)| times.iter().any(|t| t.is_some()))
+        .collect();
+    if rows.is_empty() {
+        return;
+    }
+    rows.sort_by_key(|(_day, _times, total)| std::cmp::Reverse(*total));
+    let grand_total: Duration = rows.iter().map(|(_day, _times, total)| *total).sum();
+
+    println!();
+    println!(
+        "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}  {:>6}",
+        "Day", "Parse", "Part 1", "Part 2", "Total", "Share"
+    );
+    for (day, times, total) in &rows {
+        println!(
+            "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}  {:>5.1}%",
+            day,
+            format_time(times[0]),
+            format_time(times[1]),
+            format_time(times[2]),
+            format_time(Some(*total)),
+            100.0 * total.as_secs_f64() / grand_total.as_secs_f64()
+        );
+    }
+    println!("Total: {}", format_time(Some(grand_total)));
+}
+
+fn main() {
+    let started = SystemTime::now();
+
+    // Some of the parts take a good fraction of a second, so fewer samples keeps the whole run to
+    // a reasonable length. It's still plenty for the fast ones, which get many iterations per sample.
+    let mut c = Criterion::default().sample_size(10).configure_from_args();
+
+    bench_days!(&mut c,
+        1 => day1::Day1,
+        2 => day2::Day2,
+        3 => day3::Day3,
+        4 => day4::Day4,
+        5 => day5::Day5,
+        6 => day6::Day6,
+        7 => day7::Day7,
+        8 => day8::Day8,
+        9 => day9::Day9,
+        10 => day10::Day10,
+        11 => day11::Day11,
+        12 => day12::Day12,
+        13 => day13::Day13,
+        14 => day14::Day14,
+        15 => day15::Day15,
+        16 => day16::Day16,
+        17 => day17::Day17,
+        18 => day18::Day18,
+        19 => day19::Day19,
+        20 => day20::Day20,
+        21 => day21::Day21,
+        22 => day22::Day22,
+        23 => day23::Day23,
+        24 => day24::Day24,
+        25 => day25::Day25,
+    );
+
+    c.final_summary();
+    print_summary(started);
+}
@@ -0,0 +1,127 @@
+// The table of every day the runner knows about. Each part just gets the raw puzzle input, so that
+// days with different input types can sit in one table. This means each part parses the input for
+// itself, and the timings include parsing.
+
+use aoc_common::generate::Generate;
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use rand::rngs::StdRng;
+
+pub type PartFn = fn(&str) -> Result<Answer, ParseError>;
+
+fn part1<S: Solution>(s: &str) -> Result<Answer, ParseError> {
+    S::parse(s).map(|input| S::part1(&input))
+}
+
+fn part2<S: Solution>(s: &str) -> Result<Answer, ParseError> {
+    S::parse(s).map(|input| S::part2(&input))
+}
+
+// A day's [Generate] implementation, for the gen subcommand.
+pub struct Generator {
+    pub size: &'static str,
+    pub default_size: usize,
+    pub min_size: usize,
+    pub max_size: usize,
+    pub density: Option<&'static str>,
+    pub default_density: f64,
+    pub generate: fn(&mut StdRng, usize, f64) -> String,
+}
+
+fn generator<S: Generate>() -> Generator {
+    Generator {
+        size: S::SIZE,
+        default_size: S::DEFAULT_SIZE,
+        min_size: S::MIN_SIZE,
+        max_size: S::MAX_SIZE,
+        density: S::DENSITY,
+        default_density: S::DEFAULT_DENSITY,
+        generate: S::generate,
+    }
+}
+
+pub struct Day {
+    pub number: u8,
+    pub part1: PartFn,
+    pub part2: PartFn,
+    pub generator: Generator,
+}
+
+macro_rules! day {
+    ($number:expr, $solution:ty) => {
+        Day {
+            number: $number,
+            part1: part1::<$solution>,
+            part2: part2::<$solution>,
+            generator: generator::<$solution>(),
+        }
+    };
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        day!(1, day1::Day1),
+        day!(2, day2::Day2),
+        day!(3, day3::Day3),
+        day!(4, day4::Day4),
+        day!(5, day5::Day5),
+        day!(6, day6::Day6),
+        day!(7, day7::Day7),
+        day!(8, day8::Day8),
+        day!(9, day9::Day9),
+        day!(10, day10::Day10),
+        day!(11, day11::Day11),
+        day!(12, day12::Day12),
+        day!(13, day13::Day13),
+        day!(14, day14::Day14),
+        day!(15, day15::Day15),
+        day!(16, day16::Day16),
+        day!(17, day17::Day17),
+        day!(18, day18::Day18),
+        day!(19, day19::Day19),
+        day!(20, day20::Day20),
+        day!(21, day21::Day21),
+        day!(22, day22::Day22),
+        day!(23, day23::Day23),
+        day!(24, day24::Day24),
+        day!(25, day25::Day25),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use std::panic;
+
+    // Every day's generated inputs should parse, and both parts should solve them, from the smallest
+    // size the day allows to a couple bigger. The days' own tests check the answers, where the
+    // generator knows what they should be.
+    #[test]
+    fn generated_inputs_can_be_solved() {
+        for day in all() {
+            let g = &day.generator;
+            for size in g.min_size..=g.max_size.min(g.min_size + 2) {
+                for seed in 0..3 {
+                    let mut rng = StdRng::seed_from_u64(seed);
+                    let s = (g.generate)(&mut rng, size, g.default_density);
+                    for (part, f) in [(1, day.part1), (2, day.part2)] {
+                        let context = format!(
+                            "day {} part {}, size {}, seed {}",
+                            day.number, part, size, seed
+                        );
+                        match panic::catch_unwind(|| f(&s)) {
+                            // Random networks don't always synchronise, and that's a real answer
+                            // rather than a bug.
+                            Ok(Ok(Answer::Failed(_))) if (day.number, part) == (8, 2) => {}
+                            Ok(Ok(Answer::Failed(e))) => panic!("{}: failed: {}", context, e),
+                            Ok(Ok(_answer)) => {}
+                            Ok(Err(e)) => panic!("{}: {}", context, e.render()),
+                            Err(_) => panic!("{}: panicked", context),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
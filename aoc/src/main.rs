@@ -0,0 +1,328 @@
+mod days;
+
+use aoc_common::input::read_input;
+use aoc_common::parse::ParseError;
+use aoc_common::report::{self, format_duration, Format};
+use aoc_common::solution::Answer;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use days::{Day, PartFn};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::panic;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log what the solutions are doing to stderr: -v for debug messages, -vv for trace as well.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with --all.
+    Run(RunArgs),
+    /// Print a random puzzle input for a day, or what each day's --size and --density mean with
+    /// --list.
+    Gen(GenArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The day to run.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Only run this part of the day. Runs both parts if not given.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2), requires = "day")]
+    part: Option<u8>,
+
+    /// Path to the puzzle input, or - to read it from stdin. Defaults to inputs/dayNN.txt.
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+
+    /// Run every day, and print a table of the answers and timings.
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// How to print the answers: text for people, or json for one JSON object per part.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// The day to generate an input for.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "list")]
+    day: Option<u8>,
+
+    /// How big the input should be. What this counts depends on the day - see --list.
+    #[arg(long, requires = "day")]
+    size: Option<usize>,
+
+    /// For the days that use it, a probability between 0 and 1 - see --list.
+    #[arg(long, value_parser = parse_density, requires = "day")]
+    density: Option<f64>,
+
+    /// Seed for the random number generator, to get the same input again. A random seed is used
+    /// if not given, and printed to stderr.
+    #[arg(long, requires = "day")]
+    seed: Option<u64>,
+
+    /// List what --size and --density mean for each day, along with their defaults.
+    #[arg(long, conflicts_with = "day")]
+    list: bool,
+}
+
+fn parse_density(s: &str) -> Result<f64, String> {
+    let density: f64 = s.parse().map_err(|_| format!("{:?} isn't a number", s))?;
+    if (0.0..=1.0).contains(&density) {
+        Ok(density)
+    } else {
+        Err("should be between 0 and 1".to_string())
+    }
+}
+
+// clap's version of [Format], which can't derive ValueEnum itself without aoc-common depending on
+// clap.
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl From<OutputFormat> for Format {
+    fn from(format: OutputFormat) -> Self {
+        match format {
+            OutputFormat::Text => Format::Text,
+            OutputFormat::Json => Format::Json,
+        }
+    }
+}
+
+fn time(part: PartFn, input: &str) -> (Result<Answer, ParseError>, Duration) {
+    let start = Instant::now();
+    let answer = part(input);
+    (answer, start.elapsed())
+}
+
+fn run_one(day: &Day, part: Option<u8>, input: Option<&str>, format: Format) -> Result<(), String> {
+    let input = read_input(day.number, input).map_err(|e| e.to_string())?;
+
+    let parts: Vec<(u8, PartFn)> = vec![(1, day.part1), (2, day.part2)]
+        .into_iter()
+        .filter(|(n, _f)| part.is_none() || part == Some(*n))
+        .collect();
+
+    for (n, f) in parts {
+        let (answer, elapsed) = time(f, &input);
+        let answer = answer.map_err(|e| {
+            format!(
+                "couldn't parse the input for day {}: {}",
+                day.number,
+                e.render()
+            )
+        })?;
+        report::print_answer(format, day.number, n, &answer, elapsed);
+    }
+    Ok(())
+}
+
+fn run_all(days: &[Day]) {
+    // Anything that goes wrong for a single day gets reported in its row of the table, rather than
+    // stopping the whole run. Parse errors and the reasons parts failed are too long to fit in the
+    // table, so they're listed underneath it.
+    let mut parse_errors = vec![];
+    let mut failures = vec![];
+    let mut run_part =
+        |day: u8, part: u8, f: PartFn, input: &str| match panic::catch_unwind(|| time(f, input)) {
+            Ok((Ok(Answer::Unsolved(_)), _elapsed)) => ("-".to_string(), None),
+            Ok((Ok(Answer::Failed(e)), _elapsed)) => {
+                failures.push(format!("Day {}, part {}: {}", day, part, e));
+                ("failed".to_string(), None)
+            }
+            Ok((Ok(answer), elapsed)) => (answer.to_string(), Some(elapsed)),
+            Ok((Err(e), _elapsed)) => {
+                parse_errors.push(format!("Day {}, part {}: {}", day, part, e));
+                ("parse error".to_string(), None)
+            }
+            Err(_) => ("panicked".to_string(), None),
+        };
+
+    let mut rows = vec![];
+    let mut total = Duration::ZERO;
+
+    for day in days {
+        let row = match read_input(day.number, None) {
+            Err(_) => (
+                day.number,
+                ("no input".to_string(), None),
+                ("no input".to_string(), None),
+            ),
+            Ok(input) => (
+                day.number,
+                run_part(day.number, 1, day.part1, &input),
+                run_part(day.number, 2, day.part2, &input),
+            ),
+        };
+        total += row.1 .1.unwrap_or_default() + row.2 .1.unwrap_or_default();
+        rows.push(row);
+    }
+
+    let width = rows
+        .iter()
+        .map(|(_day, (a1, _t1), (a2, _t2))| a1.len().max(a2.len()))
+        .max()
+        .unwrap_or(0)
+        .max("Part 1".len());
+    let time_to_string = |t: &Option<Duration>| t.map(format_duration).unwrap_or_default();
+
+    println!(
+        "{:>3}  {:<width$}  {:>10}  {:<width$}  {:>10}",
+        "Day", "Part 1", "Time", "Part 2", "Time"
+    );
+    for (day, (a1, t1), (a2, t2)) in &rows {
+        println!(
+            "{:>3}  {:<width$}  {:>10}  {:<width$}  {:>10}",
+            day,
+            a1,
+            time_to_string(t1),
+            a2,
+            time_to_string(t2)
+        );
+    }
+    println!("Total time: {}", format_duration(total));
+
+    if !parse_errors.is_empty() {
+        println!();
+        println!("Couldn't parse the inputs for:");
+        for e in parse_errors {
+            println!("  {}", e);
+        }
+    }
+    if !failures.is_empty() {
+        println!();
+        println!("Couldn't solve:");
+        for e in failures {
+            println!("  {}", e);
+        }
+    }
+}
+
+// Like [run_all], but with a line of JSON for each part instead of the table. Failures get a line
+// with an "error" rather than stopping the run.
+fn run_all_json(days: &[Day]) {
+    for day in days {
+        let input = read_input(day.number, None);
+        for (n, f) in [(1, day.part1), (2, day.part2)] {
+            let line = match &input {
+                Err(_) => report::error_json(day.number, n, "no input"),
+                Ok(input) => match panic::catch_unwind(|| time(f, input)) {
+                    Ok((Ok(answer), elapsed)) => {
+                        report::answer_json(day.number, n, &answer, elapsed)
+                    }
+                    Ok((Err(e), _elapsed)) => {
+                        report::error_json(day.number, n, &format!("parse error: {}", e))
+                    }
+                    Err(_) => report::error_json(day.number, n, "panicked"),
+                },
+            };
+            println!("{}", line);
+        }
+    }
+}
+
+fn list_generators(days: &[Day]) {
+    for day in days {
+        let g = &day.generator;
+        println!("Day {}:", day.number);
+        let sizes = match (g.min_size, g.max_size) {
+            (min, usize::MAX) => format!("at least {}", min),
+            (min, max) => format!("{} to {}", min, max),
+        };
+        println!(
+            "  --size: {} (default {}, {})",
+            g.size, g.default_size, sizes
+        );
+        if let Some(density) = g.density {
+            println!(
+                "  --density: probability of {} (default {})",
+                density, g.default_density
+            );
+        }
+    }
+}
+
+fn generate(day: &Day, args: &GenArgs) -> Result<(), String> {
+    let g = &day.generator;
+    let size = args.size.unwrap_or(g.default_size);
+    if !(g.min_size..=g.max_size).contains(&size) {
+        return Err(match g.max_size {
+            usize::MAX => format!(
+                "day {} needs a --size of at least {}",
+                day.number, g.min_size
+            ),
+            max => format!(
+                "day {} needs a --size from {} to {}",
+                day.number, g.min_size, max
+            ),
+        });
+    }
+    let seed = args.seed.unwrap_or_else(|| {
+        let seed = rand::random();
+        eprintln!("Generating with --seed {}", seed);
+        seed
+    });
+    let mut rng = StdRng::seed_from_u64(seed);
+    let density = args.density.unwrap_or(g.default_density);
+    print!("{}", (g.generate)(&mut rng, size, density));
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    aoc_common::logging::init(cli.verbose);
+    let days = days::all();
+
+    match cli.command {
+        Command::Run(args) => {
+            if args.all {
+                match args.format.into() {
+                    Format::Text => run_all(&days),
+                    Format::Json => run_all_json(&days),
+                }
+                return ExitCode::SUCCESS;
+            }
+
+            let day_number = args.day.expect("clap requires --day without --all");
+            let day = &days[day_number as usize - 1];
+            match run_one(day, args.part, args.input.as_deref(), args.format.into()) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Gen(args) => {
+            if args.list {
+                list_generators(&days);
+                return ExitCode::SUCCESS;
+            }
+
+            let day_number = args.day.expect("clap requires --day without --list");
+            match generate(&days[day_number as usize - 1], &args) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
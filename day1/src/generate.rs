@@ -0,0 +1,41 @@
+use crate::Day1;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+impl Generate for Day1 {
+    const SIZE: &'static str = "lines";
+    const DEFAULT_SIZE: usize = 1000;
+    const DENSITY: Option<&'static str> = Some("a digit being spelled out as a word");
+    const DEFAULT_DENSITY: f64 = 0.4;
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String {
+        let mut result = String::new();
+        for _ in 0..size {
+            // Some digits, each with some junk letters before it. Part 1 needs at least one of the
+            // digits to be numeric, so make sure there's one.
+            let digits = rng.gen_range(1..=5);
+            let numeric = rng.gen_range(0..digits);
+            for i in 0..digits {
+                for _ in 0..rng.gen_range(0..=5) {
+                    result.push(rng.gen_range('a'..='z'));
+                }
+                let digit = rng.gen_range(1..=9);
+                if i != numeric && rng.gen_bool(density) {
+                    result.push_str(WORDS[digit - 1]);
+                } else {
+                    result.push_str(&digit.to_string());
+                }
+            }
+            for _ in 0..rng.gen_range(0..=5) {
+                result.push(rng.gen_range('a'..='z'));
+            }
+            result.push('\n');
+        }
+        result
+    }
+}
@@ -0,0 +1,159 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+mod generate;
+
+fn no_digits(s: &str) -> ParseError {
+    ParseError::new(s, "a line with at least one digit")
+}
+
+// None if there aren't any digits. That's allowed in the input, as long as there are digits
+// spelled out in words for part 2.
+fn string_to_number(s: &str) -> Option<usize> {
+    let digits: Vec<usize> = s
+        .chars()
+        .filter_map(|x| x.to_digit(10))
+        .map(|x| x as usize)
+        .collect();
+
+    // The first and last digit - will deal with only one digit in the string.
+    Some(10 * digits.first()? + digits.last()?)
+}
+
+fn string_to_number2(s: &str) -> Result<usize, ParseError> {
+    // More annoying - we also need to look for digits that are spelled out.
+
+    let digit_map = HashMap::from([
+        ("one", 1usize),
+        ("two", 2),
+        ("three", 3),
+        ("four", 4),
+        ("five", 5),
+        ("six", 6),
+        ("seven", 7),
+        ("eight", 8),
+        ("nine", 9),
+    ]);
+
+    // I did this mutably rather than functionally like above.
+    let mut position_to_digit = HashMap::<usize, usize>::new();
+
+    for (i, c) in s.char_indices() {
+        match c.to_digit(10) {
+            Some(x) => {
+                position_to_digit.insert(i, x as usize);
+            }
+            None => {
+                // See if this maps any of the digits.
+                for (&digit_word, digit_value) in digit_map.iter() {
+                    if s[i..].starts_with(digit_word) {
+                        position_to_digit.insert(i, *digit_value);
+                    }
+                }
+            }
+        }
+    }
+
+    let keys = position_to_digit.keys();
+    let min_key = keys.clone().min().ok_or_else(|| no_digits(s))?;
+    let max_key = keys.clone().max().ok_or_else(|| no_digits(s))?;
+
+    Ok(10 * position_to_digit[min_key] + position_to_digit[max_key])
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        // Every line needs a digit of some sort. Numeric digits are a special case of that.
+        for line in s.trim().lines() {
+            string_to_number2(line).map_err(|e| e.locate(s))?;
+        }
+        Ok(s.trim().lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        // Lines with only spelled out digits parse fine, but part 1 can't do anything with them.
+        let mut total = 0;
+        for (i, line) in lines.iter().enumerate() {
+            match string_to_number(line) {
+                Some(number) => total += number,
+                None => return Answer::Failed(format!("line {} has no numeric digits", i + 1)),
+            }
+        }
+        total.into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .map(|line| string_to_number2(line).expect("Checked there was a digit when parsing"))
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+    const EXAMPLE2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn parses_example() {
+        let lines = Day1::parse(EXAMPLE1).unwrap();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "1abc2");
+    }
+
+    #[test]
+    fn rejects_lines_without_digits() {
+        let e = Day1::parse("1abc2\nabc\n").unwrap_err();
+        assert_eq!(e.expected(), "a line with at least one digit");
+        assert_eq!(e.location().unwrap().line, 2);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day1::part1(&input), Answer::Integer(142));
+    }
+
+    #[test]
+    fn part1_needs_numeric_digits() {
+        let input = Day1::parse(EXAMPLE2).unwrap();
+        assert_eq!(
+            Day1::part1(&input),
+            Answer::Failed("line 2 has no numeric digits".to_string())
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day1::part2(&input), Answer::Integer(281));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day01.txt and answers/day01.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day1>(1);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::Day10;
+use aoc_common::generate::{Generate, Outline};
+use aoc_common::grid::{Dir, Grid, Position};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+fn pipe(dirs: [Dir; 2]) -> char {
+    use Dir::*;
+    match dirs {
+        [Up, Down] | [Down, Up] => '|',
+        [Left, Right] | [Right, Left] => '-',
+        [Up, Right] | [Right, Up] => 'L',
+        [Up, Left] | [Left, Up] => 'J',
+        [Left, Down] | [Down, Left] => '7',
+        [Down, Right] | [Right, Down] => 'F',
+        _ => panic!("A pipe can't go {:?}", dirs),
+    }
+}
+
+fn dir_between(from: Position, to: Position) -> Dir {
+    if to.0 < from.0 {
+        Dir::Up
+    } else if to.0 > from.0 {
+        Dir::Down
+    } else if to.1 < from.1 {
+        Dir::Left
+    } else {
+        Dir::Right
+    }
+}
+
+// Where each line between cells of the outline ends up, after stretching each cell to be 1 to 3
+// tiles across. There's a tile of ground around the edge.
+fn stretch(rng: &mut StdRng, cells: usize) -> Vec<usize> {
+    let mut lines = vec![1];
+    for _ in 0..cells {
+        lines.push(lines.last().unwrap() + rng.gen_range(1..=3));
+    }
+    lines
+}
+
+// The sketch, along with the length of the loop and the number of tiles inside it.
+fn sketch(rng: &mut StdRng, size: usize, density: f64) -> (String, usize, usize) {
+    // The loop is the outline of a random tree, stretched out so that there are tiles inside it.
+    let outline = Outline::random(rng, (size / 4).max(1));
+    let rows = stretch(rng, outline.filled.height());
+    let cols = stretch(rng, outline.filled.width());
+    let height = rows.last().unwrap() + 2;
+    let width = cols.last().unwrap() + 2;
+
+    let corners: Vec<Position> = outline
+        .corners
+        .iter()
+        .map(|Position(r, c)| Position(rows[*r], cols[*c]))
+        .collect();
+    let mut tiles = vec![];
+    for (i, from) in corners.iter().enumerate() {
+        let to = corners[(i + 1) % corners.len()];
+        let mut position = *from;
+        while position != to {
+            tiles.push(position);
+            position = match dir_between(position, to) {
+                Dir::Up => Position(position.0 - 1, position.1),
+                Dir::Down => Position(position.0 + 1, position.1),
+                Dir::Left => Position(position.0, position.1 - 1),
+                Dir::Right => Position(position.0, position.1 + 1),
+            };
+        }
+    }
+
+    let mut grid = Grid::new(height, width, None);
+    for (i, tile) in tiles.iter().enumerate() {
+        let previous = tiles[(i + tiles.len() - 1) % tiles.len()];
+        let next = tiles[(i + 1) % tiles.len()];
+        grid[*tile] = Some(pipe([
+            dir_between(*tile, previous),
+            dir_between(*tile, next),
+        ]));
+    }
+
+    // Junk pipes inside the loop, to make part 2 harder. They aren't allowed outside the loop,
+    // because the solution assumes there's only one loop, and junk could make another.
+    let mut inside = 0;
+    let mut chars = Grid::new(height, width, '.');
+    for (position, tile) in grid.iter() {
+        let Position(row, col) = position;
+        chars[position] = match tile {
+            Some(pipe) => *pipe,
+            None => {
+                let is_inside = (rows[0]..=*rows.last().unwrap()).contains(&row)
+                    && (cols[0]..=*cols.last().unwrap()).contains(&col)
+                    && outline.is_inside(
+                        rows.iter().rposition(|r| *r <= row).unwrap(),
+                        cols.iter().rposition(|c| *c <= col).unwrap(),
+                    );
+                if is_inside {
+                    inside += 1;
+                }
+                if is_inside && rng.gen_bool(density) {
+                    *PIPES.choose(rng).unwrap()
+                } else {
+                    '.'
+                }
+            }
+        };
+    }
+
+    // Start somewhere on the loop. Anything next to the start that isn't part of the loop is
+    // ground, so there's no doubt about which way the loop goes.
+    let start = *tiles.choose(rng).unwrap();
+    chars[start] = 'S';
+    for neighbour in grid.neighbours(start) {
+        if grid[neighbour].is_none() {
+            chars[neighbour] = '.';
+        }
+    }
+
+    let mut result = String::new();
+    for row in chars.rows() {
+        result.extend(row);
+        result.push('\n');
+    }
+    (result, tiles.len(), inside)
+}
+
+impl Generate for Day10 {
+    const SIZE: &'static str = "rows and columns, roughly";
+    const DEFAULT_SIZE: usize = 140;
+    const DENSITY: Option<&'static str> = Some("a junk pipe on each tile inside the loop");
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String {
+        sketch(rng, size, density).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solution::{Answer, Solution};
+    use rand::SeedableRng;
+
+    #[test]
+    fn finds_the_sketched_loop() {
+        for seed in 0..10 {
+            let (s, length, inside) = sketch(&mut StdRng::seed_from_u64(seed), 20, 0.5);
+            let input = Day10::parse(&s).unwrap();
+            assert_eq!(Day10::part1(&input), Answer::from(length / 2));
+            assert_eq!(Day10::part2(&input), Answer::from(inside));
+        }
+    }
+}
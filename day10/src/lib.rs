@@ -0,0 +1,402 @@
+use aoc_common::grid::{Dir, Grid, Position};
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashSet;
+use tracing::{debug, trace};
+use Dir::*;
+
+#[derive(Debug, Clone)]
+enum Square {
+    Pipe(Dir, Dir),
+    Ground,
+    Unknown,
+}
+use Square::*;
+
+// The puzzle calls the map of pipes a sketch.
+#[derive(Debug, Clone)]
+pub struct Sketch {
+    rabbit_pos: Position,
+    grid: Grid<Square>,
+}
+
+enum MoveResult {
+    FailedToMove,
+    MovedOntoStartSpace,
+    Moved { new_pos: Position, new_dir: Dir },
+}
+use MoveResult::*;
+
+mod generate;
+
+impl Sketch {
+    fn move_(&self, starting_pos: Position, dir: Dir) -> MoveResult {
+        // From a starting point in the grid, if you move in some direction, does that work?
+        // If None, that means you can't move this way - either you've hit a wall, or a pipe that isn't connected.
+        // If Some(pos, dir), then you've moved this way and maybe have changed direction.
+        let Some(new_pos) = self.grid.step(starting_pos, dir) else {
+            // We'd be moving off the edge of the grid.
+            return FailedToMove;
+        };
+
+        // Now check what square we're moving onto.
+        match &self.grid[new_pos] {
+            Ground => FailedToMove,
+            Unknown => MovedOntoStartSpace,
+            Pipe(dir1, dir2) => {
+                // OK, so we've hit a pipe. One of these ends needs to connect to us - the other one tells us
+                // where we'll point next.
+                //
+                // The convention I've gone for is that the pipe ends point outwards, so I need to flip my direction.
+                if *dir1 == dir.flip() {
+                    Moved {
+                        new_pos,
+                        new_dir: *dir2,
+                    }
+                } else if *dir2 == dir.flip() {
+                    Moved {
+                        new_pos,
+                        new_dir: *dir1,
+                    }
+                } else {
+                    FailedToMove
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct FindLoopResult {
+    // The loop, starting and ending where the rabbit is
+    loop_: Vec<Position>,
+}
+
+fn find_loop(g: &Sketch) -> FindLoopResult {
+    // Plan: For each direction, start the rabbit off there. See if we ever get back to the starting point.
+    for start_dir in Dir::ALL {
+        let mut loop_ = vec![g.rabbit_pos];
+        let mut dir = start_dir;
+
+        // Everything has degree 2 here so I don't need to worry about getting stuck inside
+        // a smaller loop - only check if I get back to the start.
+        let success = loop {
+            match g.move_(loop_[loop_.len() - 1], dir) {
+                FailedToMove => break false,
+                MovedOntoStartSpace => {
+                    loop_.push(g.rabbit_pos);
+                    break true;
+                }
+                Moved { new_pos, new_dir } => {
+                    loop_.push(new_pos);
+                    dir = new_dir;
+                }
+            }
+        };
+
+        if success {
+            return FindLoopResult { loop_ };
+        }
+    }
+
+    panic!("Failed to find any direction which made the rabbit run in a loop.")
+}
+
+fn parse(s: &str) -> Result<Sketch, ParseError> {
+    let grid = Grid::parse(s, "a pipe, '.' or 'S'", |c| match c {
+        '.' => Some(Ground),
+        '|' => Some(Pipe(Up, Down)),
+        '-' => Some(Pipe(Left, Right)),
+        'L' => Some(Pipe(Up, Right)),
+        'J' => Some(Pipe(Up, Left)),
+        '7' => Some(Pipe(Left, Down)),
+        'F' => Some(Pipe(Down, Right)),
+        'S' => Some(Unknown),
+        _ => None,
+    })
+    .map_err(|e| e.locate(s))?;
+
+    let rabbit_pos = grid
+        .find(|square| matches!(square, Unknown))
+        .ok_or_else(|| ParseError::new(parse::end_of(s), "a starting square 'S'").locate(s))?;
+    Ok(Sketch { grid, rabbit_pos })
+}
+
+fn find_points_inside_loop(sketch: &Sketch, loop_: &Vec<Position>) -> Vec<Position> {
+    // Find the points that are inside [loop_].
+    // This function isn't quite right. We do a flood fill from the outside, which might mean we
+    // end up including points that are inside a different loop. Hopefully the input won't have those.
+    //
+    // To deal with the fact that we can squeeze between pipes, we do a flood fill on a grid that's
+    // twice the size. So effectively each square on the grid gets split into quarters.
+    let grid = &sketch.grid;
+    let mut big_grid = Grid::new(2 * grid.height(), 2 * grid.width(), false);
+
+    // This is a huge block of code to identify which cells are neighbours in the big grid.
+    // To do this, we need to look at where the pipes are, which might block off a section of that cell.
+    let neighbours = |big_grid: &Grid<bool>, position: Position| -> Vec<Position> {
+        let Position(x, y) = position;
+        let grid_contains_pipe_with_this_dir = |x: usize, y: usize, dir| -> bool {
+            match grid[Position(x, y)] {
+                Ground => false,
+                Unknown => panic!("Should not have any unknown cells at this stage"),
+                Pipe(dir1, dir2) => dir1 == dir || dir2 == dir,
+            }
+        };
+
+        // Return the set of neighbours of a node in the big grid. This is quite gnarly, unfortunately.
+        let even_x = x % 2 == 0;
+        let even_y = y % 2 == 0;
+
+        let mut result = vec![];
+
+        // This bounds checks, and deals with checking the pipe.
+        let push_neighbour_in_dir_if_no_pipe =
+            |result: &mut Vec<Position>, move_dir: Dir, if_no_pipe_in_dir: Option<Dir>| {
+                let new_position = big_grid.step(position, move_dir);
+
+                match new_position {
+                    None => {
+                        // We'd be moving out of the grid. Don't need to do anything.
+                    }
+                    Some(new_position) => {
+                        let should_add = match if_no_pipe_in_dir {
+                            None => {
+                                // We've been told to unconditionally add this neighbour.
+                                true
+                            }
+                            Some(dir) => !grid_contains_pipe_with_this_dir(x / 2, y / 2, dir),
+                        };
+                        if should_add {
+                            result.push(new_position);
+                        }
+                    }
+                }
+            };
+
+        if even_x && even_y {
+            // We're in the top-left of one of the little boxes, so:
+            // - We always have the top and left neighbours (assuming they're in bounds)
+            // - We have the right neighbour if this cell doesn't contain an Up pipe
+            // - We have the down neighbour if this cell doesn't contain a Left pipe
+            push_neighbour_in_dir_if_no_pipe(&mut result, Up, None);
+            push_neighbour_in_dir_if_no_pipe(&mut result, Left, None);
+            push_neighbour_in_dir_if_no_pipe(&mut result, Right, Some(Up));
+            push_neighbour_in_dir_if_no_pipe(&mut result, Down, Some(Left));
+        } else if !even_x && even_y {
+            // We're in the bottom-left of one of the cells, so:
+            // - We always have the bottom and left neighbours (assuming they're in bounds)
+            // - We have the right neighbour if this cell doesn't contain a Down pipe
+            // - We have the top neighbout if this cell doesn't contain a Left pipe.
+            push_neighbour_in_dir_if_no_pipe(&mut result, Up, Some(Left));
+            push_neighbour_in_dir_if_no_pipe(&mut result, Left, None);
+            push_neighbour_in_dir_if_no_pipe(&mut result, Right, Some(Down));
+            push_neighbour_in_dir_if_no_pipe(&mut result, Down, None);
+        } else if even_x && !even_y {
+            // We're in the top-right of one of the cells, so:
+            // - We always have the top and right neighbours (assuming they're in bounds)
+            // - We have the left neighbour if this cell doesn't contain an Up pipe.
+            // - We have the down neighbour if this cell doesn't contain a Right pipe.
+            push_neighbour_in_dir_if_no_pipe(&mut result, Up, None);
+            push_neighbour_in_dir_if_no_pipe(&mut result, Left, Some(Up));
+            push_neighbour_in_dir_if_no_pipe(&mut result, Right, None);
+            push_neighbour_in_dir_if_no_pipe(&mut result, Down, Some(Right));
+        } else if !even_x && !even_y {
+            // We're in the bottom-right of one of the cells, so:
+            // - We always have the bottom and right neighbours (assuming they're in bounds)
+            // - We have the left neighbour if this cell doesn't contain a Down pipe.
+            // - We have the up neighbour if this cell doesn't contain a Right pipe.
+            push_neighbour_in_dir_if_no_pipe(&mut result, Up, Some(Right));
+            push_neighbour_in_dir_if_no_pipe(&mut result, Left, Some(Down));
+            push_neighbour_in_dir_if_no_pipe(&mut result, Right, None);
+            push_neighbour_in_dir_if_no_pipe(&mut result, Down, None);
+        };
+        result
+    };
+
+    // Now do a flood fill from the outside of the big grid.
+    let mut in_queue: Vec<Position> = vec![];
+    let mut fully_processed: HashSet<Position> = HashSet::new();
+
+    // Add the border cells
+    for i in 0..big_grid.height() {
+        in_queue.push(Position(i, 0));
+        in_queue.push(Position(i, big_grid.width() - 1));
+    }
+    for j in 0..big_grid.width() {
+        in_queue.push(Position(0, j));
+        in_queue.push(Position(big_grid.height() - 1, j));
+    }
+
+    // Do the flood fill.
+    while let Some(a) = in_queue.pop() {
+        if !fully_processed.contains(&a) {
+            big_grid[a] = true;
+            fully_processed.insert(a);
+            for neighbour in neighbours(&big_grid, a) {
+                in_queue.push(neighbour);
+            }
+        }
+    }
+
+    // And then count up which points are inside the loop! We'll assume a point is inside the loop
+    // if all 4 of its quarters weren't hit by the flood fill - this should (hopefully?) exclude points
+    // that are on the loop itself.
+    let mut points_inside_loop = vec![];
+    for x in 0..grid.height() {
+        for y in 0..grid.width() {
+            // Did we find any sections of this point in the flood fill?
+            let flood_fill_points: usize = ([
+                big_grid[Position(2 * x, 2 * y)],
+                big_grid[Position(2 * x + 1, 2 * y)],
+                big_grid[Position(2 * x, 2 * y + 1)],
+                big_grid[Position(2 * x + 1, 2 * y + 1)],
+            ])
+            .iter()
+            .map(|x| if *x { 1 } else { 0 })
+            .sum();
+
+            if flood_fill_points == 0 {
+                points_inside_loop.push(Position(x, y))
+            }
+        }
+    }
+
+    trace!(?loop_, "the loop");
+    debug!(
+        ?points_inside_loop,
+        "found {} points inside the loop",
+        points_inside_loop.len()
+    );
+    points_inside_loop
+}
+
+fn get_direction(start_point: Position, end_point: Position) -> Dir {
+    if end_point.0 == start_point.0 + 1 {
+        return Down;
+    }
+    if end_point.0 + 1 == start_point.0 {
+        return Up;
+    }
+    if end_point.1 + 1 == start_point.1 {
+        return Left;
+    }
+    if end_point.1 == start_point.1 + 1 {
+        return Right;
+    }
+    panic!("Doesn't look like the end_point is 1 away from start_point");
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Sketch;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        // Part 1: Find the loop the rabbit is in, which requires figuring out the pipe
+        // it's on.
+        let FindLoopResult { loop_ } = find_loop(grid);
+
+        loop_
+            .iter()
+            .enumerate()
+            .map(|(i, _point)| i.min(loop_.len() - 1 - i))
+            .max()
+            .expect("Must be at least one point in the loop.")
+            .into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+        let FindLoopResult { loop_ } = find_loop(&grid);
+
+        // Modify the grid so we don't have any unknown squares. Use the loop we've found to fill it in.
+        // Get the outward facing directions from the starting point, and set the pipe.
+        let dir1 = get_direction(grid.rabbit_pos, loop_[1]);
+        let dir2 = get_direction(grid.rabbit_pos, loop_[loop_.len() - 2]);
+        grid.grid[grid.rabbit_pos] = Pipe(dir1, dir2);
+
+        // Part 2: We want to do a flood fill from the outside to try and identify which points are inside
+        // the big loop. Technically this will identify points that are inside any loop - hopefully the question
+        // doesn't have other loops in!
+        find_points_inside_loop(&grid, &loop_).len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+    const EXAMPLE2: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+    const EXAMPLE3: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+    #[test]
+    fn parses_example() {
+        let sketch = Day10::parse(EXAMPLE1).unwrap();
+        assert_eq!(sketch.rabbit_pos, Position(2, 0));
+        assert_eq!((sketch.grid.height(), sketch.grid.width()), (5, 5));
+    }
+
+    #[test]
+    fn rejects_a_missing_start() {
+        let e = Day10::parse("F7\nLJ\n").unwrap_err();
+        assert_eq!(e.token(), "");
+        assert_eq!(e.location().unwrap().line, 2);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day10::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day10::part1(&input), Answer::Integer(8));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day10::part2(&input), Answer::Integer(4));
+    }
+
+    #[test]
+    fn part2_example_with_junk_pipes() {
+        let input = Day10::parse(EXAMPLE3).unwrap();
+        assert_eq!(Day10::part2(&input), Answer::Integer(10));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day10.txt and answers/day10.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day10>(10);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::Day11;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day11 {
+    const SIZE: &'static str = "rows and columns";
+    const DEFAULT_SIZE: usize = 140;
+    const DENSITY: Option<&'static str> = Some("a galaxy in each cell");
+    const DEFAULT_DENSITY: f64 = 0.02;
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String {
+        // Some rows and columns need to be empty for the universe to expand, and at this density
+        // enough of them are by chance.
+        let mut result = String::new();
+        for _ in 0..size {
+            result.extend((0..size).map(|_| if rng.gen_bool(density) { '#' } else { '.' }));
+            result.push('\n');
+        }
+        result
+    }
+}
@@ -0,0 +1,161 @@
+use aoc_common::grid::Grid;
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+mod generate;
+
+fn expand_grid_and_get_galaxies(
+    grid: &[Vec<bool>],
+    expansion_amount: usize,
+) -> HashSet<(usize, usize)> {
+    // Expand grid. Because of part 2, representing this as a grid no longer works!
+    let height = grid.len();
+    let width = grid[0].len();
+
+    // Find empty rows and empty columns
+    let empty_rows: Vec<usize> = (0..height)
+        .filter(|i| {
+            let any_nonempty = (0..width).any(|j| grid[*i][j]);
+            !any_nonempty
+        })
+        .collect();
+    let empty_cols: Vec<usize> = (0..width)
+        .filter(|j| {
+            let any_nonempty = (0..height).any(|i| grid[i][*j]);
+            !any_nonempty
+        })
+        .collect();
+
+    // Create a mapping from old row/column to new row/column.
+    let mut old_to_new_row: HashMap<usize, usize> = HashMap::new();
+    let mut old_to_new_col: HashMap<usize, usize> = HashMap::new();
+
+    for i in 0..height {
+        // The location this maps to in the new grid is the number of empty rows before this one.
+        // (This is quadratic, I should keep a running total, but whatever.)
+        let real_i = i + (empty_rows.iter().filter(|j| **j < i).count() * expansion_amount);
+        old_to_new_row.insert(i, real_i);
+    }
+    for i in 0..width {
+        let real_i = i + (empty_cols.iter().filter(|j| **j < i).count() * expansion_amount);
+        old_to_new_col.insert(i, real_i);
+    }
+
+    let mut result = HashSet::new();
+
+    for i in 0..height {
+        for j in 0..width {
+            if grid[i][j] {
+                let new_i = old_to_new_row
+                    .get(&i)
+                    .expect("BUG: Couldn't find mapping for row");
+                let new_j = old_to_new_col
+                    .get(&j)
+                    .expect("BUG: Couldn't find mapping for col");
+
+                result.insert((*new_i, *new_j));
+            }
+        }
+    }
+
+    result
+}
+
+fn parse(s: &str) -> Result<Vec<Vec<bool>>, ParseError> {
+    let grid = Grid::parse(s, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .map_err(|e| e.locate(s))?;
+    Ok(grid.rows().map(|row| row.to_vec()).collect())
+}
+
+pub fn solve(grid: &[Vec<bool>], expansion_amount: usize) -> usize {
+    // Expand the grid and get galaxies
+    let galaxies = expand_grid_and_get_galaxies(grid, expansion_amount);
+
+    // Find distances between each galaxy, sum them up, return!
+    let mut result = 0;
+    for (x1, x2) in &galaxies {
+        for (y1, y2) in &galaxies {
+            result += x1.abs_diff(*y1) + x2.abs_diff(*y2);
+        }
+    }
+
+    // And divide by 2, because we counted each pair twice above.
+    result / 2
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Vec<bool>>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        solve(grid, 1).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        solve(grid, 1_000_000 - 1).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    #[test]
+    fn parses_example() {
+        let grid = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(grid.len(), 10);
+        let galaxies = grid.iter().flatten().filter(|g| **g).count();
+        assert_eq!(galaxies, 9);
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        let e = Day11::parse("\n").unwrap_err();
+        assert_eq!(e.expected(), "at least one row");
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input), Answer::Integer(374));
+    }
+
+    #[test]
+    fn smaller_expansions() {
+        // The example doesn't give an answer for part 2's expansion, just these smaller ones.
+        let grid = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(solve(&grid, 10 - 1), 1030);
+        assert_eq!(solve(&grid, 100 - 1), 8410);
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day11.txt and answers/day11.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day11>(11);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::Day12;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day12 {
+    const SIZE: &'static str = "rows";
+    const DEFAULT_SIZE: usize = 1000;
+    const DENSITY: Option<&'static str> = Some("a spring being unknown");
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String {
+        let mut result = String::new();
+        for _ in 0..size {
+            // Pick the real springs first, and then hide some of them, so there's always at least
+            // one arrangement.
+            let springs: Vec<bool> = loop {
+                let length = rng.gen_range(1..=20);
+                let springs: Vec<bool> = (0..length).map(|_| rng.gen_bool(0.4)).collect();
+                if springs.contains(&true) {
+                    break springs;
+                }
+            };
+            let groups: Vec<String> = springs
+                .split(|damaged| !damaged)
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect();
+
+            for damaged in springs {
+                result.push(match (rng.gen_bool(density), damaged) {
+                    (true, _) => '?',
+                    (false, true) => '#',
+                    (false, false) => '.',
+                });
+            }
+            result.push_str(&format!(" {}\n", groups.join(",")));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number_of_ways;
+    use aoc_common::solution::Solution;
+    use rand::SeedableRng;
+
+    #[test]
+    fn generated_rows_can_be_arranged() {
+        for seed in 0..10 {
+            let s = Day12::generate(&mut StdRng::seed_from_u64(seed), 50, 0.5);
+            let input = Day12::parse(&s).unwrap();
+            for (springs, groups) in &input {
+                assert!(number_of_ways(springs, groups) >= 1);
+                crate::tests::check_arrangements(springs, groups);
+            }
+        }
+    }
+}
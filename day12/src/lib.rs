@@ -0,0 +1,475 @@
+use aoc_common::parse::{self, char_token, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use rand::Rng;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/*
+OK, so how do we count the possibilities? The obvious answer is some sort of dynamic programming thing.
+
+Let f(S, M) be the number of ways of filling in unknowns in S so it matches pattern M.
+Let g(S, M) be the number of ways of filling in unknowns in S so it matches pattern M, with no spaces at the end.
+
+If len(M) == 1, then it's not too bad to do this directly.
+
+If len(M) > 1, then we can partition these based on "when does the first one finish", add a space after it.
+*/
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    Empty,
+    Full,
+    Unknown,
+}
+use Status::*;
+
+mod generate;
+
+impl Status {
+    fn is_fillable(&self) -> bool {
+        match self {
+            Unknown | Full => true,
+            Empty => false,
+        }
+    }
+
+    fn is_blankable(&self) -> bool {
+        match self {
+            Unknown | Empty => true,
+            Full => false,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+struct Key {
+    status_index: Range<usize>,
+    pattern_index: Range<usize>,
+}
+
+// The ways we can start filling in the part of the row [key] is about, when there's at least one
+// group left to place: either start the first group right here, or leave the first spring working
+// and start it later. Each comes with how many springs it fills in - (damaged, then working) - and
+// where that leaves us.
+//
+// Putting the group first means going through these in order gives the arrangements in
+// lexicographic order, where '#' comes before '.'.
+fn steps(
+    full_status: &[Status],
+    full_pattern: &[usize],
+    key: &Key,
+) -> impl Iterator<Item = ((usize, usize), Key)> {
+    let s = &full_status[key.status_index.clone()];
+    let pattern = &full_pattern[key.pattern_index.clone()];
+    let prefix_length = {
+        if pattern.len() == 1 {
+            pattern[0]
+        } else {
+            pattern[0] + 1
+        }
+    };
+    if s.len() < prefix_length {
+        // Bail out early - the length of the string isn't going to be long enough.
+        return [None, None].into_iter().flatten();
+    }
+
+    // Case 2 (only possible if first pattern[0] cells are fillable, and pattern[0] cell is blankable).
+    // The pattern starts at position 0.
+    let pattern_starting_here_is_possible = {
+        let first_cells_are_fillable = s[0..pattern[0]].iter().all(|s| s.is_fillable());
+        let next_cell_is_blankable = {
+            // We only need to check this if pattern.len() > 1
+            (pattern.len() == 1) || (pattern.len() > 1 && s[pattern[0]].is_blankable())
+        };
+        first_cells_are_fillable && (next_cell_is_blankable || pattern.len() == 1)
+    };
+    let group_here = pattern_starting_here_is_possible.then(|| {
+        let status_index = key.status_index.start + prefix_length..key.status_index.end;
+        let pattern_index = key.pattern_index.start + 1..key.pattern_index.end;
+
+        let key = Key {
+            status_index,
+            pattern_index,
+        };
+        ((pattern[0], prefix_length - pattern[0]), key)
+    });
+
+    // Case 1 (only possible if first cell is blankable) - the pattern starts at position >= 1.
+    let group_later = s[0].is_blankable().then(|| {
+        let status_index = key.status_index.start + 1..key.status_index.end;
+        let key = Key {
+            status_index,
+            pattern_index: key.pattern_index.clone(),
+        };
+        ((0, 1), key)
+    });
+
+    [group_here, group_later].into_iter().flatten()
+}
+
+// This needs to be a [fn], not a closure, because it's recursive.
+// All arguments other than key here are just meant to capture from the environment.
+fn solve(
+    full_status: &[Status],
+    full_pattern: &[usize],
+    key: Key,
+    cache: &mut HashMap<Key, usize>,
+) -> usize {
+    // Check cache
+    if cache.contains_key(&key) {
+        return *cache.get(&key).unwrap();
+    }
+
+    let s = &full_status[key.status_index.clone()];
+    let pattern = &full_pattern[key.pattern_index.clone()];
+
+    // Solve, assuming that we've included everything we'll need in the cache.
+    let result = match (s.len(), pattern.len()) {
+        (_, 0) => {
+            // Base case. 0 is true if we can fill in all squares with Empty.
+            // (This covers the case when s is empty as well.)
+            if s.iter().all(|t| t.is_blankable()) {
+                1
+            } else {
+                0
+            }
+        }
+        (0, _) => {
+            // Base case - we have a non-empty pattern but an empty string, so nothing we can do.
+            0
+        }
+        _ => {
+            // Otherwise, both s and pattern are non-empty.
+            steps(full_status, full_pattern, &key)
+                .map(|(_filled, key)| solve(full_status, full_pattern, key, cache))
+                .sum()
+        }
+    };
+    cache.insert(key, result);
+    result
+}
+
+pub fn number_of_ways(s: &[Status], pattern: &[usize]) -> usize {
+    Arrangements::new(s, pattern).count()
+}
+
+// Every way of filling in the unknown springs in a row, worked out from the same table of counts as
+// [number_of_ways]. Knowing how many arrangements there are down each branch means we can go
+// straight to any one of them, without listing the ones before it.
+pub struct Arrangements<'a> {
+    status: &'a [Status],
+    pattern: &'a [usize],
+    cache: HashMap<Key, usize>,
+}
+
+impl<'a> Arrangements<'a> {
+    pub fn new(status: &'a [Status], pattern: &'a [usize]) -> Self {
+        let mut cache = HashMap::new();
+        solve(
+            status,
+            pattern,
+            Arrangements::start(status, pattern),
+            &mut cache,
+        );
+        Arrangements {
+            status,
+            pattern,
+            cache,
+        }
+    }
+
+    fn start(status: &[Status], pattern: &[usize]) -> Key {
+        Key {
+            status_index: 0..status.len(),
+            pattern_index: 0..pattern.len(),
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.ways(&Arrangements::start(self.status, self.pattern))
+    }
+
+    // Everything reachable from the start got filled in by [new].
+    fn ways(&self, key: &Key) -> usize {
+        self.cache[key]
+    }
+
+    // The steps from [key] that lead to at least one arrangement, with how many each leads to.
+    fn branches(&self, key: &Key) -> Vec<((usize, usize), Key, usize)> {
+        steps(self.status, self.pattern, key)
+            .map(|(filled, key)| {
+                let ways = self.ways(&key);
+                (filled, key, ways)
+            })
+            .filter(|(_filled, _key, ways)| *ways > 0)
+            .collect()
+    }
+
+    fn fill(springs: &mut Vec<Status>, (damaged, working): (usize, usize)) {
+        springs.extend([Full].repeat(damaged));
+        springs.extend([Empty].repeat(working));
+    }
+
+    // Once all the groups are placed, the rest of the springs are working.
+    fn finish(&self, mut springs: Vec<Status>) -> Vec<Status> {
+        springs.resize(self.status.len(), Empty);
+        springs
+    }
+
+    // The [k]th arrangement in lexicographic order, counting from 0.
+    pub fn kth(&self, mut k: usize) -> Option<Vec<Status>> {
+        if k >= self.count() {
+            return None;
+        }
+        let mut key = Arrangements::start(self.status, self.pattern);
+        let mut springs = vec![];
+        while !key.pattern_index.is_empty() {
+            // Skip over whole branches until we get to the one [k] is in.
+            for (filled, next, ways) in self.branches(&key) {
+                if k < ways {
+                    Arrangements::fill(&mut springs, filled);
+                    key = next;
+                    break;
+                }
+                k -= ways;
+            }
+        }
+        Some(self.finish(springs))
+    }
+
+    // All of the arrangements in lexicographic order, worked out as they're needed. Branches with
+    // nothing down them are never taken, so there's no backtracking.
+    pub fn iter(&self) -> impl Iterator<Item = Vec<Status>> + '_ {
+        let start = Arrangements::start(self.status, self.pattern);
+        let mut stack = vec![];
+        if self.ways(&start) > 0 {
+            stack.push((vec![], start));
+        }
+        std::iter::from_fn(move || loop {
+            let (springs, key) = stack.pop()?;
+            if key.pattern_index.is_empty() {
+                return Some(self.finish(springs));
+            }
+            // Backwards, so the first branch comes off the stack first.
+            for (filled, next, _ways) in self.branches(&key).into_iter().rev() {
+                let mut springs = springs.clone();
+                Arrangements::fill(&mut springs, filled);
+                stack.push((springs, next));
+            }
+        })
+    }
+
+    // One of the arrangements, each as likely as any other.
+    pub fn sample(&self, rng: &mut impl Rng) -> Option<Vec<Status>> {
+        match self.count() {
+            0 => None,
+            count => self.kth(rng.gen_range(0..count)),
+        }
+    }
+}
+
+// The springs the way the puzzle writes them.
+pub fn show(springs: &[Status]) -> String {
+    springs
+        .iter()
+        .map(|s| match s {
+            Empty => '.',
+            Full => '#',
+            Unknown => '?',
+        })
+        .collect()
+}
+
+// One line of the input: the springs, and the sizes of the groups of damaged springs.
+pub type Row = (Vec<Status>, Vec<usize>);
+
+fn parse_line(line: &str) -> Result<Row, ParseError> {
+    let (s, pattern) = parse::split_once(line, " ")?;
+
+    let s: Vec<Status> = s
+        .char_indices()
+        .map(|(i, c)| match c {
+            '?' => Ok(Unknown),
+            '.' => Ok(Empty),
+            '#' => Ok(Full),
+            _ => Err(ParseError::new(char_token(s, i), "'?', '.' or '#'")),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let pattern: Vec<usize> = pattern
+        .trim()
+        .split(',')
+        .map(parse::number)
+        .collect::<Result<_, _>>()?;
+    Ok((s, pattern))
+}
+
+fn parse(s: &str) -> Result<Vec<Row>, ParseError> {
+    s.trim()
+        .lines()
+        .map(parse_line)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(s))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Row>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(rows: &Self::Input) -> Answer {
+        rows.iter()
+            .map(|(status, pattern)| number_of_ways(status, pattern))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(rows: &Self::Input) -> Answer {
+        // Part 2 - copy each of the cases by 5 times!
+        let mut total = 0;
+        for (status, pattern) in rows {
+            // Expand each of them by 5. I did this in a bit of a lazy way.
+            let mut new_status = status.clone();
+            for _ in 0..4 {
+                new_status.push(Unknown);
+                new_status.extend(status.iter());
+            }
+            let mut new_pattern = pattern.clone();
+            for _ in 0..4 {
+                new_pattern.extend(pattern.iter());
+            }
+
+            total += number_of_ways(&new_status, &new_pattern);
+        }
+        total.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn parses_example() {
+        let rows = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(rows.len(), 6);
+        assert_eq!(
+            rows[0],
+            (
+                vec![Unknown, Unknown, Unknown, Empty, Full, Full, Full],
+                vec![1, 1, 3]
+            )
+        );
+    }
+
+    #[test]
+    fn counts_each_row() {
+        let rows = Day12::parse(EXAMPLE).unwrap();
+        let counts: Vec<usize> = rows.iter().map(|(s, p)| number_of_ways(s, p)).collect();
+        assert_eq!(counts, vec![1, 4, 1, 1, 4, 10]);
+    }
+
+    // The groups of damaged springs in a row with nothing unknown.
+    fn groups(springs: &[Status]) -> Vec<usize> {
+        springs
+            .split(|s| *s != Full)
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len())
+            .collect()
+    }
+
+    pub fn check_arrangements(status: &[Status], pattern: &[usize]) {
+        let arrangements = Arrangements::new(status, pattern);
+        let all: Vec<Vec<Status>> = arrangements.iter().collect();
+        assert_eq!(all.len(), number_of_ways(status, pattern));
+
+        let shown: Vec<String> = all.iter().map(|springs| show(springs)).collect();
+        // Sorted, which also means there aren't any repeats.
+        assert!(shown.windows(2).all(|w| w[0] < w[1]), "{shown:?}");
+        for (k, springs) in all.iter().enumerate() {
+            assert_eq!(groups(springs), pattern);
+            assert!(status
+                .iter()
+                .zip(springs)
+                .all(|(s, filled)| *s == Unknown || s == filled));
+            assert_eq!(arrangements.kth(k).as_ref(), Some(springs));
+        }
+        assert_eq!(arrangements.kth(all.len()), None);
+    }
+
+    #[test]
+    fn lists_arrangements() {
+        let rows = Day12::parse(EXAMPLE).unwrap();
+        for (status, pattern) in &rows {
+            check_arrangements(status, pattern);
+        }
+
+        let (status, pattern) = &rows[1];
+        let arrangements: Vec<String> = Arrangements::new(status, pattern)
+            .iter()
+            .map(|springs| show(&springs))
+            .collect();
+        assert_eq!(
+            arrangements,
+            vec![
+                ".#...#....###.",
+                ".#....#...###.",
+                "..#..#....###.",
+                "..#...#...###.",
+            ]
+        );
+
+        // Nothing fits, so there's nothing to list.
+        let (status, pattern) = parse_line("#.# 3").unwrap();
+        let arrangements = Arrangements::new(&status, &pattern);
+        assert_eq!(arrangements.iter().next(), None);
+        assert_eq!(arrangements.kth(0), None);
+    }
+
+    #[test]
+    fn samples_every_arrangement() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use std::collections::HashSet;
+
+        let rows = Day12::parse(EXAMPLE).unwrap();
+        let (status, pattern) = &rows[5];
+        let arrangements = Arrangements::new(status, pattern);
+        let mut rng = StdRng::seed_from_u64(12);
+        let seen: HashSet<Vec<Status>> = (0..200)
+            .map(|_| arrangements.sample(&mut rng).unwrap())
+            .collect();
+        assert_eq!(seen.len(), 10);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input), Answer::Integer(21));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), Answer::Integer(525152));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day12.txt and answers/day12.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day12>(12);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{find_other_reflection_line, find_reflection_line, parse_grid, Day13};
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// A pattern that reflects across a vertical line between columns [col] - 1 and [col], and would
+// also reflect across a horizontal line between rows [row] - 1 and [row] if it weren't for one
+// smudge. Either of these might not be the only line that works, so the caller checks.
+fn pattern(
+    rng: &mut StdRng,
+    height: usize,
+    width: usize,
+    row: usize,
+    col: usize,
+) -> Vec<Vec<bool>> {
+    let mirror_col = |c: usize| (2 * col).checked_sub(c + 1).filter(|c| *c < width);
+    let mirror_row = |r: usize| (2 * row).checked_sub(r + 1).filter(|r| *r < height);
+
+    // Reflecting across both lines splits the cells up into groups of (up to) 4, which all need to
+    // be the same.
+    let mut cells: Vec<Vec<Option<bool>>> = vec![vec![None; width]; height];
+    for r in 0..height {
+        for c in 0..width {
+            if cells[r][c].is_some() {
+                continue;
+            }
+            let value = rng.gen_bool(0.5);
+            for r in [Some(r), mirror_row(r)].into_iter().flatten() {
+                for c in [Some(c), mirror_col(c)].into_iter().flatten() {
+                    cells[r][c] = Some(value);
+                }
+            }
+        }
+    }
+    let mut cells: Vec<Vec<bool>> = cells
+        .into_iter()
+        .map(|row| row.into_iter().map(Option::unwrap).collect())
+        .collect();
+
+    // Now smudge a cell which has a reflection across the horizontal line, but not the vertical
+    // one, so it only breaks the horizontal line.
+    let rows: Vec<usize> = (0..height).filter(|r| mirror_row(*r).is_some()).collect();
+    let cols: Vec<usize> = (0..width).filter(|c| mirror_col(*c).is_none()).collect();
+    let r = rows[rng.gen_range(0..rows.len())];
+    let c = cols[rng.gen_range(0..cols.len())];
+    cells[r][c] = !cells[r][c];
+    cells
+}
+
+impl Generate for Day13 {
+    const SIZE: &'static str = "patterns";
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        let mut patterns = vec![];
+        while patterns.len() < size {
+            let height = rng.gen_range(5..=17);
+            let width = rng.gen_range(5..=17);
+            let row = rng.gen_range(1..height);
+            // The smudge needs a column with no reflection, so the line can't be in the middle.
+            let col = rng.gen_range(1..width);
+            if 2 * col == width {
+                continue;
+            }
+            let mut cells = pattern(rng, height, width, row, col);
+
+            // Half the time, swap which way the lines go.
+            if rng.gen_bool(0.5) {
+                cells = (0..width)
+                    .map(|c| (0..height).map(|r| cells[r][c]).collect())
+                    .collect();
+            }
+            let s: String = cells
+                .iter()
+                .map(|row| {
+                    let mut line: String = row.iter().map(|b| if *b { '#' } else { '.' }).collect();
+                    line.push('\n');
+                    line
+                })
+                .collect();
+
+            // Random patterns often have extra lines of reflection by chance, especially small
+            // ones, so just try again when that happens.
+            let grid = parse_grid(&s).expect("Generated an invalid pattern");
+            if find_reflection_line(&grid).len() == 1
+                && find_other_reflection_line(&grid).len() == 1
+            {
+                patterns.push(s);
+            }
+        }
+        patterns.join("\n")
+    }
+}
@@ -0,0 +1,262 @@
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+mod generate;
+
+#[derive(Debug, Clone)]
+pub struct Grid(Vec<Vec<char>>);
+
+fn parse_grid(s: &str) -> Result<Grid, ParseError> {
+    let grid =
+        aoc_common::grid::Grid::parse(s, "'#' or '.'", |c| matches!(c, '#' | '.').then_some(c))?;
+    Ok(Grid(grid.rows().map(|row| row.to_vec()).collect()))
+}
+
+#[derive(Debug)]
+struct GridLineIds {
+    // A map from row or column numbers -> ID. Two values have the same key iff they're the same string.
+    by_row: HashMap<usize, usize>,
+    by_col: HashMap<usize, usize>,
+}
+
+fn to_ids(g: &Grid) -> GridLineIds {
+    let mut string_to_row_id = HashMap::new();
+    let mut string_to_col_id = HashMap::new();
+    let mut by_row = HashMap::new();
+    let mut by_col = HashMap::new();
+
+    // Insert strings by row.
+    for (row_id, row) in g.0.iter().enumerate() {
+        let string: String = row.iter().collect();
+        let size = string_to_row_id.len();
+        let resolved_row_id = string_to_row_id.entry(string).or_insert(size);
+        by_row.insert(row_id, *resolved_row_id);
+    }
+
+    // Insert strings by column
+    for col_id in 0..g.0[0].len() {
+        let string: String = (0..g.0.len()).map(|j| g.0[j][col_id]).collect();
+        let size = string_to_col_id.len();
+        let resolved_col_id = string_to_col_id.entry(string).or_insert(size);
+        by_col.insert(col_id, *resolved_col_id);
+    }
+
+    GridLineIds { by_row, by_col }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum Dir {
+    Horizontal,
+    Vertical,
+}
+
+fn is_reflection_line(g: &GridLineIds, index: usize, dir: &Dir) -> bool {
+    // index is the value just *after* the reflection line - equivalently, the number
+    // of lines on one side of the reflection line.
+
+    let ids = {
+        match dir {
+            Dir::Horizontal => &g.by_row,
+            Dir::Vertical => &g.by_col,
+        }
+    };
+
+    if index == 0 || index >= ids.len() {
+        // We have to reflect at least one line.
+        panic!("Index out of bounds in [is_reflection_line]")
+    }
+
+    // All of the indices before index need to be reflected correctly.
+    (0..index).all(|x| {
+        let reflected_x = 2 * index - 1 - x;
+
+        if reflected_x >= ids.len() {
+            // Nothing to check - this point would reflect off the graph.
+            true
+        } else {
+            ids[&x] == ids[&reflected_x]
+        }
+    })
+}
+
+fn find_reflection_line(g: &Grid) -> Vec<(usize, Dir)> {
+    let ids = to_ids(g);
+
+    let mut result = vec![];
+
+    let results_horizontal = (1..g.0.len()).filter_map(|i| {
+        let dir = Dir::Horizontal;
+        if is_reflection_line(&ids, i, &dir) {
+            Some((i, dir))
+        } else {
+            None
+        }
+    });
+    let results_vertical = (1..g.0[0].len()).filter_map(|i| {
+        let dir = Dir::Vertical;
+        if is_reflection_line(&ids, i, &dir) {
+            Some((i, dir))
+        } else {
+            None
+        }
+    });
+    result.extend(results_horizontal);
+    result.extend(results_vertical);
+    result
+}
+
+fn find_other_reflection_line(g: &Grid) -> Vec<(usize, Dir)> {
+    // There is exactly one point in the grid that can be flipped to get a different reflection line. Find it.
+    let original_line = find_reflection_line(g);
+
+    // Not a smart way, just brute-force each other position, and find which one works. A better way would be
+    // to identify which things are "almost" reflection lines, to filter down faster, but this is easily
+    // fast enough in a compiled language.
+    let mut grid = g.clone();
+    let mut result = vec![];
+
+    for row_id in 0..grid.0.len() {
+        for col_id in 0..grid.0[0].len() {
+            // Flip this point, and check it. I'm lazy - I try both possibilities, even though one of
+            // them will have been what's already in the grid.
+            grid.0[row_id][col_id] = '#';
+
+            let lines: Vec<(usize, Dir)> = find_reflection_line(&grid)
+                .iter()
+                .filter(|x| !original_line.contains(x))
+                .copied()
+                .collect();
+
+            grid.0[row_id][col_id] = '.';
+            let lines2: Vec<(usize, Dir)> = find_reflection_line(&grid)
+                .iter()
+                .filter(|x| !original_line.contains(x))
+                .copied()
+                .collect();
+
+            grid.0[row_id][col_id] = g.0[row_id][col_id];
+
+            result.extend(lines);
+            result.extend(lines2);
+        }
+    }
+
+    // The same line can be found by flipping different points, so sort before removing duplicates.
+    result.sort();
+    result.dedup();
+    result
+}
+
+fn line_to_score(x: usize, dir: &Dir) -> usize {
+    x * match dir {
+        Dir::Horizontal => 100,
+        Dir::Vertical => 1,
+    }
+}
+
+fn total_score(grids: &[Grid], find_lines: fn(&Grid) -> Vec<(usize, Dir)>) -> usize {
+    grids
+        .iter()
+        .map(|grid| match &find_lines(grid)[..] {
+            [(index, dir)] => line_to_score(*index, dir),
+            _ => unreachable!("Checked there's exactly one line of reflection when parsing"),
+        })
+        .sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let mut grids = vec![];
+        for case in s.trim().split("\n\n") {
+            let grid = parse_grid(case).map_err(|e| e.locate(s))?;
+
+            // Both parts need exactly one line of reflection to score the pattern.
+            for find_lines in [find_reflection_line, find_other_reflection_line] {
+                if find_lines(&grid).len() != 1 {
+                    let e = ParseError::new(case, "a pattern with exactly one line of reflection");
+                    return Err(e.locate(s));
+                }
+            }
+            grids.push(grid);
+        }
+        Ok(grids)
+    }
+
+    fn part1(grids: &Self::Input) -> Answer {
+        total_score(grids, find_reflection_line).into()
+    }
+
+    fn part2(grids: &Self::Input) -> Answer {
+        total_score(grids, find_other_reflection_line).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    #[test]
+    fn parses_example() {
+        let grids = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[0].0.len(), 7);
+        assert_eq!(grids[0].0[0].len(), 9);
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        let e = Day13::parse("").unwrap_err();
+        assert_eq!(e.expected(), "at least one row");
+    }
+
+    #[test]
+    fn rejects_patterns_without_a_reflection() {
+        let e = Day13::parse("#.\n..\n\n#.\n.#\n").unwrap_err();
+        assert_eq!(
+            e.expected(),
+            "a pattern with exactly one line of reflection"
+        );
+        assert_eq!(e.location().unwrap().line, 1);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input), Answer::Integer(405));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part2(&input), Answer::Integer(400));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day13.txt and answers/day13.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day13>(13);
+    }
+}
@@ -0,0 +1,30 @@
+use crate::Day14;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day14 {
+    const SIZE: &'static str = "rows and columns";
+    const DEFAULT_SIZE: usize = 100;
+    const DENSITY: Option<&'static str> = Some("a rounded rock in each cell");
+    const DEFAULT_DENSITY: f64 = 0.2;
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String {
+        // About half as many cube-shaped rocks as rounded ones, like the real inputs.
+        let mut result = String::new();
+        for _ in 0..size {
+            result.extend((0..size).map(|_| {
+                let x: f64 = rng.gen();
+                if x < density {
+                    'O'
+                } else if x < density * 1.5 {
+                    '#'
+                } else {
+                    '.'
+                }
+            }));
+            result.push('\n');
+        }
+        result
+    }
+}
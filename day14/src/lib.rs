@@ -0,0 +1,205 @@
+use aoc_common::grid::{Dir, Grid, Position};
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+use tracing::{debug, trace};
+use Dir::*;
+
+mod generate;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Square {
+    Empty,
+    MoveableBlock,
+    StaticBlock,
+}
+use Square::*;
+
+impl From<Square> for char {
+    fn from(square: Square) -> char {
+        match square {
+            Empty => '.',
+            MoveableBlock => 'O',
+            StaticBlock => '#',
+        }
+    }
+}
+
+fn parse(s: &str) -> Result<Grid<Square>, ParseError> {
+    Grid::parse(s, "'.', 'O' or '#'", |c| match c {
+        '.' => Some(Empty),
+        'O' => Some(MoveableBlock),
+        '#' => Some(StaticBlock),
+        _ => None,
+    })
+    .map_err(|e| e.locate(s))
+}
+
+fn move_block_if_moveable(grid: &mut Grid<Square>, position: Position, dir: Dir) {
+    if grid[position] != MoveableBlock {
+        // Nothing to do, this block isn't moveable.
+        return;
+    }
+
+    // Find the place it should fall to: keep stepping until we'd leave the grid or hit something.
+    let mut target_position = position;
+    while let Some(next_) = grid.step(target_position, dir) {
+        if grid[next_] != Empty {
+            break;
+        }
+        target_position = next_;
+    }
+
+    grid[position] = Empty;
+    grid[target_position] = MoveableBlock;
+}
+
+fn move_all_blocks(grid: &mut Grid<Square>, dir: Dir) {
+    // Move all blocks in this dir.
+
+    // Iterate through the blocks in the right order! So if we're moving Up (north), we need to go top-to-bottom, etc.
+    // This is a bit silly - I should precache this so I don't recompute it each time - but whatever.
+    let mut positions: Vec<Position> = grid.positions().collect();
+
+    let sort_key = |&p: &Position| -> i64 {
+        let Position(x, y) = p;
+        match dir {
+            Up => x as i64,
+            Down => -(x as i64),
+            Left => y as i64,
+            Right => -(y as i64),
+        }
+    };
+
+    positions.sort_by_key(sort_key);
+
+    for p in positions {
+        move_block_if_moveable(grid, p, dir);
+    }
+}
+
+fn load(grid: &Grid<Square>) -> usize {
+    let mut total = 0;
+    for (i, line) in grid.rows().enumerate() {
+        let height = grid.height() - i;
+        let number_of_blocks = line.iter().filter(|c| **c == MoveableBlock).count();
+        total += height * number_of_blocks;
+    }
+    total
+}
+
+pub fn solve_part1(grid: &mut Grid<Square>) -> usize {
+    move_all_blocks(grid, Up);
+    load(grid)
+}
+
+pub fn solve_part2(grid: &mut Grid<Square>) -> usize {
+    let mut state_to_index: HashMap<Grid<Square>, usize> = HashMap::new();
+
+    let mut number_of_cycles = 0usize;
+
+    // Loop until we find a cycle, and return the cycle length from here.
+    let cycle_length = loop {
+        move_all_blocks(grid, Up);
+        move_all_blocks(grid, Left);
+        move_all_blocks(grid, Down);
+        move_all_blocks(grid, Right);
+
+        number_of_cycles += 1;
+
+        if let Some(old_index) = state_to_index.get(grid) {
+            let cycle_length = number_of_cycles - old_index;
+            break cycle_length;
+        }
+
+        state_to_index.insert(grid.clone(), number_of_cycles);
+    };
+
+    debug!(
+        "found a cycle of length {} after {} spin cycles, so we can skip ahead",
+        cycle_length, number_of_cycles
+    );
+    let target = 1_000_000_000usize;
+
+    number_of_cycles += ((target - number_of_cycles) / cycle_length) * cycle_length;
+    assert!(number_of_cycles <= target);
+
+    while number_of_cycles < target {
+        move_all_blocks(grid, Up);
+        move_all_blocks(grid, Left);
+        move_all_blocks(grid, Down);
+        move_all_blocks(grid, Right);
+
+        number_of_cycles += 1;
+    }
+
+    load(grid)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<Square>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    // Both parts tilt the grid in place, so they each work on their own copy.
+    fn part1(grid: &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+        let result = solve_part1(&mut grid);
+        trace!("grid after tilting north:\n{}", grid);
+        result.into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        solve_part2(&mut grid.clone()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn parses_example() {
+        let grid = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!((grid.height(), grid.width()), (10, 10));
+        assert_eq!(grid[Position(0, 0)], MoveableBlock);
+        assert_eq!(grid[Position(0, 5)], StaticBlock);
+        // Printing it back out gives the input.
+        assert_eq!(grid.to_string(), EXAMPLE);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part1(&input), Answer::Integer(136));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(Day14::part2(&input), Answer::Integer(64));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day14.txt and answers/day14.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day14>(14);
+    }
+}
@@ -0,0 +1,33 @@
+use crate::Day15;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day15 {
+    const SIZE: &'static str = "steps";
+    const DEFAULT_SIZE: usize = 4000;
+    const DENSITY: Option<&'static str> = Some("a step removing a lens");
+    const DEFAULT_DENSITY: f64 = 0.3;
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String {
+        // Labels get reused, so lenses get replaced and removed again later.
+        let labels: Vec<String> = (0..size / 4 + 1)
+            .map(|_| {
+                let len = rng.gen_range(2..=6);
+                (0..len).map(|_| rng.gen_range('a'..='z')).collect()
+            })
+            .collect();
+
+        let steps: Vec<String> = (0..size)
+            .map(|_| {
+                let label = &labels[rng.gen_range(0..labels.len())];
+                if rng.gen_bool(density) {
+                    format!("{}-", label)
+                } else {
+                    format!("{}={}", label, rng.gen_range(1..=9))
+                }
+            })
+            .collect();
+        steps.join(",") + "\n"
+    }
+}
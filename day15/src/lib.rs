@@ -0,0 +1,221 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use tracing::trace;
+
+mod generate;
+
+fn string_hash(s: &str) -> usize {
+    s.chars().map(|c| c as u8).fold(0, |acc, x| {
+        let acc = acc as i64;
+        let x = x as i64;
+
+        let y = ((acc + x) * 17) % 256;
+        y as u8
+    }) as usize
+}
+
+#[derive(Debug, Clone)]
+pub enum Move {
+    Add {
+        r#box: usize,
+        lens_number: usize,
+        label: String,
+    },
+    Remove {
+        r#box: usize,
+        label: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Lens {
+    label: String,
+    lens_number: usize,
+}
+
+#[derive(Debug)]
+struct Boxes(Vec<Vec<Lens>>);
+
+fn apply_move(boxes: &mut Boxes, r#move: Move) {
+    // None of this is very efficient.
+
+    match r#move {
+        Move::Remove { r#box, label } => {
+            let this_box = &mut boxes.0[r#box];
+
+            // This is linear time - could do O(log n) with some sort of tree structure - but whatever.
+            // Also unnecessarily clones the strings, but I'm not sure how to fix that.
+            boxes.0[r#box] = this_box
+                .iter()
+                .filter(|lens| {
+                    let Lens {
+                        label: this_label,
+                        lens_number: _,
+                    } = lens;
+                    *this_label != label
+                })
+                .cloned()
+                .collect();
+        }
+        Move::Add {
+            r#box,
+            lens_number,
+            label,
+        } => {
+            let this_box = &mut boxes.0[r#box];
+
+            // Does the box already contain something of this label? If so, replace the lense number.
+            let mut found_right_lens = false;
+
+            for lens in this_box.iter_mut() {
+                if lens.label == label {
+                    lens.lens_number = lens_number;
+                    found_right_lens = true;
+                }
+            }
+
+            // And if not, push it onto the end.
+            if !found_right_lens {
+                this_box.push(Lens { label, lens_number })
+            }
+        }
+    }
+}
+
+fn parse_move(s: &str) -> Result<Move, ParseError> {
+    // Moves are either:
+    // STRING-
+    // STRING=NUMBER
+
+    if let Some(label) = s.strip_suffix("-") {
+        // We're a remove move.
+        let label = label.to_string();
+        let r#box = string_hash(&label);
+        Ok(Move::Remove { r#box, label })
+    } else {
+        // We're an add move.
+        let (label, lens_number) = parse::split_once(s, "=")?;
+        let label = label.to_string();
+        let r#box = string_hash(&label);
+        let lens_number = parse::number(lens_number)?;
+        Ok(Move::Add {
+            r#box,
+            lens_number,
+            label,
+        })
+    }
+}
+
+fn solve_part2(moves: &[Move]) -> usize {
+    let mut boxes = Boxes(vec![]);
+    for _ in 0..256 {
+        boxes.0.push(vec![]);
+    }
+
+    for r#move in moves {
+        apply_move(&mut boxes, r#move.clone())
+    }
+
+    // Now compute the focusing power.
+    let mut total = 0;
+
+    for (box_index, r#box) in boxes.0.iter().enumerate() {
+        let box_index = box_index + 1;
+
+        for (lens_index, lens) in r#box.iter().enumerate() {
+            let lens_index = lens_index + 1;
+            total += box_index * lens_index * lens.lens_number
+        }
+    }
+
+    trace!(?boxes, "boxes after the whole sequence");
+    total
+}
+
+// The initialization sequence. Part 1 only needs the raw steps, but part 2 needs to know what
+// each of them does.
+#[derive(Debug)]
+pub struct Sequence {
+    pub steps: Vec<String>,
+    pub moves: Vec<Move>,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Sequence;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let steps: Vec<&str> = s.trim().split(',').collect();
+        let moves = steps
+            .iter()
+            .copied()
+            .map(parse_move)
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.locate(s))?;
+
+        Ok(Sequence {
+            steps: steps.into_iter().map(|step| step.to_string()).collect(),
+            moves,
+        })
+    }
+
+    fn part1(sequence: &Self::Input) -> Answer {
+        sequence
+            .steps
+            .iter()
+            .map(|x| string_hash(x))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(sequence: &Self::Input) -> Answer {
+        solve_part2(&sequence.moves).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+";
+
+    #[test]
+    fn hashes() {
+        assert_eq!(string_hash("HASH"), 52);
+    }
+
+    #[test]
+    fn parses_example() {
+        let sequence = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(sequence.steps.len(), 11);
+        assert!(matches!(
+            &sequence.moves[0],
+            Move::Add { r#box: 0, lens_number: 1, label } if label == "rn"
+        ));
+        assert!(matches!(
+            &sequence.moves[1],
+            Move::Remove { r#box: 0, label } if label == "cm"
+        ));
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(Day15::part1(&input), Answer::Integer(1320));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!(Day15::part2(&input), Answer::Integer(145));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day15.txt and answers/day15.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day15>(15);
+    }
+}
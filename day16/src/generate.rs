@@ -0,0 +1,26 @@
+use crate::Day16;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day16 {
+    const SIZE: &'static str = "rows and columns";
+    const DEFAULT_SIZE: usize = 110;
+    const DENSITY: Option<&'static str> = Some("a mirror or splitter in each cell");
+    const DEFAULT_DENSITY: f64 = 0.1;
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String {
+        let mut result = String::new();
+        for _ in 0..size {
+            result.extend((0..size).map(|_| {
+                if rng.gen_bool(density) {
+                    ['/', '\\', '|', '-'][rng.gen_range(0..4)]
+                } else {
+                    '.'
+                }
+            }));
+            result.push('\n');
+        }
+        result
+    }
+}
@@ -0,0 +1,228 @@
+use aoc_common::grid::{Dir, Grid, Position};
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashSet;
+use Dir::*;
+
+mod generate;
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+struct BeamState {
+    position: Position,
+    dir: Dir,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum GridSpace {
+    Empty,
+    MirrorTopLeft,
+    MirrorTopRight,
+    SplitterHorizontal,
+    SplitterVertical,
+}
+use GridSpace::*;
+
+fn adjust_dirs(beam: &BeamState, grid: &Grid<GridSpace>) -> Vec<BeamState> {
+    // When we move a beam, we need to:
+    // (1) Move its position
+    // (2) Maybe adjust its direction, to account for the fact that we're on a mirror.
+    //
+    // This function just does (2). Useful because we also use it when initialising the beam.
+    let BeamState { position, dir } = *beam;
+    let grid_space = grid[position];
+
+    let new_dirs = match (dir, grid_space) {
+        // If the spot is empty, we don't change direction
+        (dir, GridSpace::Empty) => vec![dir],
+        // The cases where the mirror starts at the top left, i.e. \
+        (Up, MirrorTopLeft) => vec![Left],
+        (Left, MirrorTopLeft) => vec![Up],
+        (Right, MirrorTopLeft) => vec![Down],
+        (Down, MirrorTopLeft) => vec![Right],
+        // The cases where the mirror starts at the top right, i.e. /
+        (Up, MirrorTopRight) => vec![Right],
+        (Right, MirrorTopRight) => vec![Up],
+        (Left, MirrorTopRight) => vec![Down],
+        (Down, MirrorTopRight) => vec![Left],
+        // The horizontal splitter.
+        (Left, SplitterHorizontal) => vec![dir],
+        (Right, SplitterHorizontal) => vec![dir],
+        (Up, SplitterHorizontal) => vec![Left, Dir::Right],
+        (Down, SplitterHorizontal) => vec![Left, Dir::Right],
+        // The vertical splitter
+        (Up, SplitterVertical) => vec![dir],
+        (Down, SplitterVertical) => vec![dir],
+        (Left, SplitterVertical) => vec![Up, Dir::Down],
+        (Right, SplitterVertical) => vec![Up, Dir::Down],
+    };
+
+    new_dirs
+        .into_iter()
+        .map(|new_dir| BeamState {
+            position,
+            dir: new_dir,
+        })
+        .collect()
+}
+
+fn move_beam(beam: &BeamState, grid: &Grid<GridSpace>) -> Vec<BeamState> {
+    let BeamState { position, dir } = beam;
+    let new_position = grid.step(*position, *dir);
+
+    match new_position {
+        None => vec![],
+        Some(new_position) => {
+            let beam_state_after_moving = BeamState {
+                position: new_position,
+                dir: *dir,
+            };
+            adjust_dirs(&beam_state_after_moving, grid)
+        }
+    }
+}
+
+fn parse_grid(s: &str) -> Result<Grid<GridSpace>, ParseError> {
+    Grid::parse(s, "'.', a mirror or a splitter", |c| match c {
+        '.' => Some(Empty),
+        '/' => Some(MirrorTopRight),
+        '\\' => Some(MirrorTopLeft),
+        '|' => Some(SplitterVertical),
+        '-' => Some(SplitterHorizontal),
+        _ => None,
+    })
+    .map_err(|e| e.locate(s))
+}
+
+fn energised_boxes_starting_from(beam: &BeamState, grid: &Grid<GridSpace>) -> usize {
+    let initial_states = {
+        // I need to adjust the directions as the beam comes into the grid (e.g. if it immediately hits a mirror).
+        adjust_dirs(beam, grid)
+    };
+
+    // Flood fill. (Our state space is BeamState, so position and a direction you're moving.)
+    let mut processed: HashSet<BeamState> = HashSet::new();
+
+    let mut queue = initial_states;
+
+    while let Some(next_) = queue.pop() {
+        processed.insert(next_);
+        let neighbours = move_beam(&next_, grid);
+
+        for neighbour in neighbours {
+            if !processed.contains(&neighbour) {
+                queue.push(neighbour);
+            }
+        }
+    }
+
+    // Then count just the positions we've reached.
+    let positions: HashSet<Position> = processed
+        .iter()
+        .map(|beam_state| beam_state.position)
+        .collect();
+    positions.len()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<GridSpace>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(s)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        energised_boxes_starting_from(
+            &BeamState {
+                position: Position(0, 0),
+                dir: Right,
+            },
+            grid,
+        )
+        .into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        // Nothing smart here, just try each possible entry point. There's probably something better I can do here.
+        // This takes about 0.5s in Rust's debug mode, so I'm pretty happy with this.
+        //
+        // I think there's a smarter thing where I could upper bound how well a fill is going to go, and then cut off some
+        // parts of the search early, but this works fine.
+        let mut edge_points: Vec<BeamState> = vec![];
+
+        let height = grid.height();
+        let width = grid.width();
+
+        for x in 0..height {
+            edge_points.push(BeamState {
+                position: Position(x, 0),
+                dir: Right,
+            });
+            edge_points.push(BeamState {
+                position: Position(x, width - 1),
+                dir: Left,
+            });
+        }
+        for y in 0..width {
+            edge_points.push(BeamState {
+                position: Position(0, y),
+                dir: Down,
+            });
+            edge_points.push(BeamState {
+                position: Position(height - 1, y),
+                dir: Left,
+            });
+        }
+
+        edge_points
+            .iter()
+            .map(|beam| energised_boxes_starting_from(beam, grid))
+            .max()
+            .unwrap()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
+
+    #[test]
+    fn parses_example() {
+        let grid = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!((grid.height(), grid.width()), (10, 10));
+        assert!(matches!(grid[Position(0, 1)], SplitterVertical));
+        assert!(matches!(grid[Position(0, 5)], MirrorTopLeft));
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part1(&input), Answer::Integer(46));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day16::parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::part2(&input), Answer::Integer(51));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day16.txt and answers/day16.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day16>(16);
+    }
+}
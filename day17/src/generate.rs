@@ -0,0 +1,21 @@
+use crate::Day17;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day17 {
+    const SIZE: &'static str = "rows and columns";
+    const DEFAULT_SIZE: usize = 141;
+    // Part 2's crucible has to go 4 squares in a straight line before it can stop.
+    const MIN_SIZE: usize = 5;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        // Heat losses are 1 to 9, so every step costs something.
+        let mut result = String::new();
+        for _ in 0..size {
+            result.extend((0..size).map(|_| char::from(b'0' + rng.gen_range(1..=9))));
+            result.push('\n');
+        }
+        result
+    }
+}
@@ -0,0 +1,248 @@
+use aoc_common::grid::{Dir, Grid, Position};
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use Dir::*;
+
+mod generate;
+
+#[derive(Hash, PartialEq, Eq, Debug)]
+struct State {
+    position: Position,
+    dir: Dir,
+    moves_so_far: usize,
+}
+
+fn neighbours(
+    grid: &Grid<usize>,
+    state: &State,
+    min_moves_before_turn: usize,
+    max_moves_before_turn: usize,
+) -> Vec<State> {
+    let mut result = vec![];
+
+    for dir in Dir::ALL {
+        // We aren't allowed to flip direction.
+        if dir == state.dir.flip() {
+            continue;
+        }
+
+        // Get the new position, bounds checking.
+        let new_position = grid.step(state.position, dir);
+
+        let moves_so_far: Option<usize> = {
+            if dir == state.dir {
+                if state.moves_so_far == max_moves_before_turn {
+                    // We must turn, so this isn't allowed.
+                    None
+                } else {
+                    Some(state.moves_so_far + 1)
+                }
+            } else {
+                if state.moves_so_far < min_moves_before_turn {
+                    // We haven't moved far enough in this direction.
+                    None
+                } else {
+                    Some(1)
+                }
+            }
+        };
+
+        if let (Some(new_position), Some(moves_so_far)) = (new_position, moves_so_far) {
+            let state = State {
+                position: new_position,
+                moves_so_far,
+                dir,
+            };
+            result.push(state)
+        }
+    }
+    result
+}
+
+pub fn dijkstra_to_bottom_right_corner(
+    grid: &Grid<usize>,
+    min_moves_before_turn: usize,
+    max_moves_before_turn: usize,
+) -> Option<usize> {
+    // Do Dijkstra to the bottom-right corner of the grid, and return the shortest route there - if
+    // there is one. The crucible might not be able to go far enough in a straight line to stop.
+
+    #[derive(PartialEq, Eq)]
+    struct BoundaryPoint {
+        state: State,
+        cost: usize,
+    }
+
+    impl PartialOrd for BoundaryPoint {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for BoundaryPoint {
+        fn cmp(&self, other: &Self) -> Ordering {
+            // Flipping the order, because BinaryHeap is a max-heap, but we want to pick the smaller element.
+            other.cost.cmp(&self.cost)
+        }
+    }
+
+    let mut queue: BinaryHeap<BoundaryPoint> = BinaryHeap::new();
+    let mut best_cost: HashMap<State, usize> = HashMap::new();
+
+    // Initialise the priority queue with starting states.
+    for dir in [Up, Down, Left, Right] {
+        let state = State {
+            position: Position(0, 0),
+            dir,
+            moves_so_far: 0,
+        };
+
+        queue.push(BoundaryPoint { state, cost: 0 })
+    }
+
+    while !queue.is_empty() {
+        // Find the boundary spot with the smallest distance.
+        let BoundaryPoint { state, cost } = queue.pop().unwrap();
+        if best_cost.contains_key(&state) {
+            // We already found this state with a smaller cost, so can continue.
+            continue;
+        }
+
+        let neighbours = neighbours(grid, &state, min_moves_before_turn, max_moves_before_turn);
+
+        // Add this state to the best distance.
+        best_cost.insert(state, cost);
+
+        // Add all of its neighbours to the queue.
+        for neighbour in neighbours {
+            if !best_cost.contains_key(&neighbour) {
+                let cost_to_neighbour_state = cost + grid[neighbour.position];
+                queue.push(BoundaryPoint {
+                    state: neighbour,
+                    cost: cost_to_neighbour_state,
+                });
+            }
+        }
+    }
+
+    // And then we want to find the lowest cost to get to any state in the bottom-right corner.
+    // We also want to make sure it's taken at least [min_moves_before_turn] to get there.
+    //
+    // I could make this faster by bailing out of the search as soon as I hit the bottom-right
+    // corner, rather than exploring the whole state space, but whatever. This works.
+    let height = grid.height();
+    let width = grid.width();
+
+    let best_score = best_cost
+        .iter()
+        .filter(|(state, _cost)| {
+            state.position == Position(height - 1, width - 1)
+                && state.moves_so_far >= min_moves_before_turn
+        })
+        .map(|(_state, cost)| *cost)
+        .min();
+
+    best_score
+}
+
+fn answer(best_score: Option<usize>) -> Answer {
+    match best_score {
+        Some(score) => score.into(),
+        None => Answer::Failed("the crucible can't stop at the bottom-right corner".to_string()),
+    }
+}
+
+fn parse(s: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::parse(s, "a digit", |c| c.to_digit(10).map(|d| d as usize)).map_err(|e| e.locate(s))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        answer(dijkstra_to_bottom_right_corner(grid, 0, 3))
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        answer(dijkstra_to_bottom_right_corner(grid, 4, 10))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+    const EXAMPLE2: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+";
+
+    #[test]
+    fn parses_example() {
+        let grid = Day17::parse(EXAMPLE1).unwrap();
+        assert_eq!((grid.height(), grid.width()), (13, 13));
+        assert_eq!(grid.row(0)[..4], [2, 4, 1, 3]);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day17::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day17::part1(&input), Answer::Integer(102));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day17::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day17::part2(&input), Answer::Integer(94));
+    }
+
+    #[test]
+    fn part2_example_with_a_long_straight() {
+        let input = Day17::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day17::part2(&input), Answer::Integer(71));
+    }
+
+    #[test]
+    fn too_small_for_part2() {
+        // The ultra crucible has to go 4 squares before it can stop, which it can't do in a 4x4
+        // grid, but it can in a 5x5 one.
+        let input = Day17::parse("1111\n1111\n1111\n1111\n").unwrap();
+        assert_eq!(Day17::part1(&input), Answer::Integer(6));
+        assert!(matches!(Day17::part2(&input), Answer::Failed(_)));
+        let input = Day17::parse("11111\n11111\n11111\n11111\n11111\n").unwrap();
+        assert_eq!(Day17::part2(&input), Answer::Integer(8));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day17.txt and answers/day17.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day17>(17);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::Day18;
+use aoc_common::generate::{Generate, Outline};
+use aoc_common::grid::Position;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// Where each line between cells of the outline ends up, after stretching each cell to be 1 to
+// [max] metres across.
+fn stretch(rng: &mut StdRng, cells: usize, max: u64) -> Vec<u64> {
+    let mut lines = vec![0];
+    for _ in 0..cells {
+        lines.push(lines.last().unwrap() + rng.gen_range(1..=max));
+    }
+    lines
+}
+
+// The corners of the loop in metres, for one of the parts.
+fn corners(rng: &mut StdRng, outline: &Outline, max: u64) -> Vec<(u64, u64)> {
+    let rows = stretch(rng, outline.filled.height(), max);
+    let cols = stretch(rng, outline.filled.width(), max);
+    outline
+        .corners
+        .iter()
+        .map(|Position(r, c)| (rows[*r], cols[*c]))
+        .collect()
+}
+
+// The size of the lagoon dug out along a loop: the area inside it from the shoelace formula, plus
+// the half of the trench that sticks out past the corners (Pick's theorem).
+fn lagoon(corners: &[(u64, u64)]) -> u64 {
+    let mut twice_area = 0;
+    let mut perimeter = 0;
+    for i in 0..corners.len() {
+        let (r1, c1) = corners[i];
+        let (r2, c2) = corners[(i + 1) % corners.len()];
+        twice_area += c1 as i64 * r2 as i64 - c2 as i64 * r1 as i64;
+        perimeter += r1.abs_diff(r2) + c1.abs_diff(c2);
+    }
+    twice_area as u64 / 2 + perimeter / 2 + 1
+}
+
+// The dig plan, along with the size of the lagoon for each part.
+fn plan(rng: &mut StdRng, size: usize) -> (String, u64, u64) {
+    // Both parts go round the same random tree, but stretched out differently - short steps for part
+    // 1, and as long as will fit in the colour for part 2. The outline of a tree with n x n nodes
+    // has about 1.6n^2 corners.
+    let outline = Outline::random(rng, ((size as f64 / 1.6).sqrt() as usize).max(1));
+    let cells = outline.filled.height() as u64;
+    let corners1 = corners(rng, &outline, 8);
+    let corners2 = corners(rng, &outline, 0xFFFFF / cells);
+
+    let step = |corners: &[(u64, u64)], i: usize| -> (char, u64) {
+        let (r1, c1) = corners[i];
+        let (r2, c2) = corners[(i + 1) % corners.len()];
+        let dir = if r2 < r1 {
+            'U'
+        } else if r2 > r1 {
+            'D'
+        } else if c2 < c1 {
+            'L'
+        } else {
+            'R'
+        };
+        (dir, r1.abs_diff(r2) + c1.abs_diff(c2))
+    };
+    let mut result = String::new();
+    for i in 0..corners1.len() {
+        let (dir1, length1) = step(&corners1, i);
+        let (dir2, length2) = step(&corners2, i);
+        let dir2 = match dir2 {
+            'R' => 0,
+            'D' => 1,
+            'L' => 2,
+            _ => 3,
+        };
+        result += &format!("{} {} (#{:05x}{})\n", dir1, length1, length2, dir2);
+    }
+    (result, lagoon(&corners1), lagoon(&corners2))
+}
+
+impl Generate for Day18 {
+    const SIZE: &'static str = "steps, roughly";
+    const DEFAULT_SIZE: usize = 700;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        plan(rng, size).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solution::{Answer, Solution};
+    use rand::SeedableRng;
+
+    #[test]
+    fn digs_out_the_planned_lagoons() {
+        for seed in 0..10 {
+            let (s, lagoon1, lagoon2) = plan(&mut StdRng::seed_from_u64(seed), 100);
+            let input = Day18::parse(&s).unwrap();
+            assert_eq!(Day18::part1(&input), Answer::from(lagoon1));
+            assert_eq!(Day18::part2(&input), Answer::from(lagoon2));
+        }
+    }
+}
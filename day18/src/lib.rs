@@ -0,0 +1,410 @@
+use aoc_common::parse::{self, char_token, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+mod generate;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+struct Position(i64, i64);
+
+#[derive(Debug)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+use Dir::*;
+
+impl Dir {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        match s {
+            "U" => Ok(Up),
+            "D" => Ok(Down),
+            "L" => Ok(Left),
+            "R" => Ok(Right),
+            _ => Err(ParseError::new(s, "a direction (U, D, L or R)")),
+        }
+    }
+
+    fn move_in_dir(&self, position: &Position, length: &usize) -> Position {
+        let length = *length as i64;
+        match self {
+            Up => Position(position.0 - length, position.1),
+            Down => Position(position.0 + length, position.1),
+            Left => Position(position.0, position.1 - length),
+            Right => Position(position.0, position.1 + length),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Step {
+    pub dir: Dir,
+    pub length: usize,
+}
+
+impl Step {
+    // Every line is "<dir> <length> (#<colour>)", and each part only reads some of these.
+    fn split(s: &str) -> Result<[&str; 3], ParseError> {
+        s.split_whitespace()
+            .collect::<Vec<&str>>()
+            .try_into()
+            .map_err(|_| ParseError::new(s, "a direction, a length and a colour"))
+    }
+
+    fn parse1(s: &str) -> Result<Self, ParseError> {
+        let [dir, length, _colour] = Step::split(s)?;
+
+        let dir = Dir::parse(dir)?;
+        let length: usize = parse::number(length)?;
+
+        Ok(Step { dir, length })
+    }
+
+    fn parse2(s: &str) -> Result<Self, ParseError> {
+        let [_dir, _length, colour] = Step::split(s)?;
+
+        let colour = parse::strip_prefix(colour, "(#")?;
+        let colour = parse::strip_suffix(colour, ")")?;
+        if colour.len() != 6 {
+            return Err(ParseError::new(colour, "6 hex digits"));
+        }
+        let length_and_dir = usize::from_str_radix(colour, 16)
+            .map_err(|_| ParseError::new(colour, "6 hex digits"))?;
+        // The length is the first 5 hex digits.
+        let length = length_and_dir / 16;
+        let dir = match length_and_dir % 16 {
+            0 => Right,
+            1 => Down,
+            2 => Left,
+            3 => Up,
+            _ => {
+                let last_digit = char_token(colour, colour.len() - 1);
+                return Err(ParseError::new(last_digit, "a direction from 0 to 3"));
+            }
+        };
+        Ok(Step { dir, length })
+    }
+}
+
+pub fn solve(steps: &[Step]) -> usize {
+    /*
+    This was fun!
+
+    High-level guide: I'm going to translate the shape I get into "mini-space".
+    Suppose the x co-ordinates of the knots I get are x_0, ..., x_{n-1} (in order, deduplicated).
+
+    Then in mini-space, I'll represent these as 0, 2, ..., 2*(n-1).
+
+    So:
+    0 --> represents a box corresponding to interval [x_0, x_0] in "real space"
+    1 --> represents a box corresponding to interval (x_0, x_1) in "real space"
+    2 --> represents a box corresponding to interval [x_1, x_1] in "real space"
+    etc. So even and odd co-ordinates are a little different.
+
+    This gives me a grid in mini-space where mini-squares correspond to disjoint regions in real space.
+    It's pretty simple to construct a formula for the real-area of a minisquare.
+
+    Then in mini-space, we look at where the boundary is, flood fill to find what's inside/outside, and
+    then convert back to find out how much real-area is contained.
+    */
+
+    // Find the boundary knots.
+    let mut knots = vec![Position(0, 0)];
+    let mut position = Position(0, 0);
+
+    for Step { dir, length } in steps {
+        position = dir.move_in_dir(&position, length);
+        knots.push(position);
+    }
+
+    // Sanity-check - we should have ended up at the start.
+    assert!(position == Position(0, 0));
+
+    // Figure out a map converting real co-ordinates to mini-coordinates.
+    // Suppose we sort the x co-ordinates of the knots in order. Then the smallest will be 0, the next smallest will be 2, etc.
+    // The reason for the jumps of 2 is it makes summing up the area at the end a bit easier, because all of the resulting
+    // squares I get correspond to disjoint ranges.
+    let all_xs: Vec<i64> = knots.iter().map(|p| p.0).collect();
+    let all_ys: Vec<i64> = knots.iter().map(|p| p.1).collect();
+
+    let make_real_to_mini = |mut v: Vec<i64>| -> HashMap<i64, i64> {
+        v.sort();
+        v.dedup();
+
+        v.into_iter()
+            .enumerate()
+            .map(|(i, x)| (x, (2 * i) as i64))
+            .collect()
+    };
+
+    let real_to_mini_x: HashMap<i64, i64> = make_real_to_mini(all_xs);
+    let real_to_mini_y: HashMap<i64, i64> = make_real_to_mini(all_ys);
+
+    let real_to_mini =
+        |p: &Position| -> Position { Position(real_to_mini_x[&p.0], real_to_mini_y[&p.1]) };
+
+    // Only contains keys for the even co-ordinates.
+    let mini_to_real_x: HashMap<i64, i64> = real_to_mini_x.iter().map(|(a, b)| (*b, *a)).collect();
+    let mini_to_real_y: HashMap<i64, i64> = real_to_mini_y.iter().map(|(a, b)| (*b, *a)).collect();
+
+    // Create a grid in mini co-ordinate space, and fill in the steps between the knots.
+    let mut boundary_cells_in_mini_space: HashSet<Position> = HashSet::new();
+
+    for (i, knot) in knots.iter().enumerate() {
+        let next_knot = {
+            if i == knots.len() - 1 {
+                &knots[0]
+            } else {
+                &knots[i + 1]
+            }
+        };
+
+        let Position(x0, y0) = real_to_mini(knot);
+        let Position(x1, y1) = real_to_mini(next_knot);
+
+        // Find the route between them.
+        let path: Vec<Position> = if x0 == x1 {
+            let min_y = y0.min(y1);
+            let max_y = y0.max(y1);
+            (min_y..=max_y).map(|y| Position(x0, y)).collect()
+        } else if y0 == y1 {
+            let min_x = x0.min(x1);
+            let max_x = x0.max(x1);
+            (min_x..=max_x).map(|x| Position(x, y0)).collect()
+        } else {
+            panic!("BUG. My knots shouldn't be not in a straight line.")
+        };
+
+        for position in path {
+            boundary_cells_in_mini_space.insert(position);
+        }
+    }
+
+    // Do a flood fill in mini-space to figure out which squares are full/empty.
+    let mut outside_cells_in_mini_space: HashSet<Position> = HashSet::new();
+    let mut explore_queue: Vec<Position> = vec![];
+
+    let min_x = *mini_to_real_x.keys().min().unwrap();
+    let max_x = *mini_to_real_x.keys().max().unwrap();
+    let min_y = *mini_to_real_y.keys().min().unwrap();
+    let max_y = *mini_to_real_y.keys().max().unwrap();
+
+    let mut insert_outside_cell = |p: Position| {
+        if !boundary_cells_in_mini_space.contains(&p) {
+            explore_queue.push(p);
+            outside_cells_in_mini_space.insert(p);
+        }
+    };
+
+    for x in min_x..=max_x {
+        for y in [min_y, max_y] {
+            let p = Position(x, y);
+            insert_outside_cell(p);
+        }
+    }
+
+    for x in [min_x, max_x] {
+        for y in min_y..=max_y {
+            let p = Position(x, y);
+            insert_outside_cell(p);
+        }
+    }
+
+    let neighbours = |p: &Position| -> Vec<Position> {
+        let mut result = vec![];
+
+        let mut push = |p: Position| {
+            if p.0 >= min_x
+                && p.0 <= max_x
+                && p.1 >= min_y
+                && p.1 <= max_y
+                && !boundary_cells_in_mini_space.contains(&p)
+            {
+                result.push(p);
+            }
+        };
+
+        push(Position(p.0 + 1, p.1));
+        push(Position(p.0 - 1, p.1));
+        push(Position(p.0, p.1 + 1));
+        push(Position(p.0, p.1 - 1));
+
+        result
+    };
+
+    while let Some(p) = explore_queue.pop() {
+        let neighbours = neighbours(&p);
+        for n in neighbours {
+            if !outside_cells_in_mini_space.contains(&n) {
+                explore_queue.push(n);
+                outside_cells_in_mini_space.insert(n);
+            }
+        }
+    }
+
+    // Now we've got all the cells in the boundary and outside, we want to sum up their area!
+    // We can find the area by mapping back to "real" co-ordinates.
+    let get_area_of_box = |p: &Position| -> usize {
+        let Position(x, y) = p;
+
+        let height = {
+            if (x % 2) == 0 {
+                1
+            } else {
+                mini_to_real_x[&(x + 1)] - mini_to_real_x[&(x - 1)] - 1
+            }
+        };
+        let width = {
+            if (y % 2) == 0 {
+                1
+            } else {
+                mini_to_real_y[&(y + 1)] - mini_to_real_y[&(y - 1)] - 1
+            }
+        };
+        assert!(height >= 0);
+        assert!(width >= 0);
+        (height * width) as usize
+    };
+
+    let mut total = 0;
+    for x in min_x..=max_x {
+        for y in min_y..=max_y {
+            let position = Position(x, y);
+            let inside_lake = match (
+                boundary_cells_in_mini_space.contains(&position),
+                outside_cells_in_mini_space.contains(&position),
+            ) {
+                (false, true) => {
+                    // This cell is outside of the shape - found in our flood fill - so we don't have to include.
+                    false
+                }
+                (true, false) => {
+                    // On the boundary, so we should include
+                    true
+                }
+                (false, false) => {
+                    // On the inside, so should include
+                    true
+                }
+                (true, true) => {
+                    // This shouldn't happen, so would be a bug in the flood fill.
+                    panic!("Shouldn't happen! We shouldn't be able to be both outside and on the boundary")
+                }
+            };
+            if inside_lake {
+                total += get_area_of_box(&position);
+            }
+        }
+    }
+    total
+}
+
+fn parse(
+    s: &str,
+    parse_step: fn(&str) -> Result<Step, ParseError>,
+) -> Result<Vec<Step>, ParseError> {
+    s.trim()
+        .lines()
+        .map(parse_step)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(s))
+}
+
+// The dig plan read both ways: part 1 uses the direction and length as written, and part 2 decodes
+// them from the colour instead.
+#[derive(Debug)]
+pub struct DigPlan {
+    pub steps1: Vec<Step>,
+    pub steps2: Vec<Step>,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = DigPlan;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        Ok(DigPlan {
+            steps1: parse(s, Step::parse1)?,
+            steps2: parse(s, Step::parse2)?,
+        })
+    }
+
+    fn part1(plan: &Self::Input) -> Answer {
+        solve(&plan.steps1).into()
+    }
+
+    fn part2(plan: &Self::Input) -> Answer {
+        solve(&plan.steps2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
+
+    #[test]
+    fn parses_example() {
+        let plan = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(plan.steps1.len(), 14);
+        assert!(matches!(
+            plan.steps1[0],
+            Step {
+                dir: Right,
+                length: 6
+            }
+        ));
+        // #70c710 is 461937 to the right.
+        assert!(matches!(
+            plan.steps2[0],
+            Step {
+                dir: Right,
+                length: 461937
+            }
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_colours() {
+        let e = Day18::parse("R 6 (#70c714)").unwrap_err();
+        assert_eq!(e.token(), "4");
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(Day18::part1(&input), Answer::Integer(62));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(Day18::part2(&input), Answer::Integer(952408144115));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day18.txt and answers/day18.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day18>(18);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::Day19;
+use aoc_common::generate::{distinct_names, Generate};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+impl Generate for Day19 {
+    const SIZE: &'static str = "workflows";
+    const DEFAULT_SIZE: usize = 550;
+    // Every workflow has a different name of up to 3 letters.
+    const MAX_SIZE: usize = 26 * 26 * 26;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        let size = size.max(1);
+        let mut names = vec!["in".to_string()];
+        names.extend(distinct_names(rng, size - 1, 3, &["in"]));
+
+        // The workflows make a tree, like the real ones do, with "in" at the root. Sending a part to
+        // a workflow that's already been reached some other way would be fine for part 1, but part
+        // 2 would have to follow every route through, and there could be a lot of them.
+        let mut results: Vec<Vec<String>> = vec![vec![]; size];
+        for i in 1..size {
+            let parent = rng.gen_range(0..i);
+            results[parent].push(names[i].clone());
+        }
+
+        let mut workflows = vec![];
+        for (name, mut results) in names.iter().zip(results) {
+            let n = rng.gen_range(2..=5);
+            while results.len() < n {
+                results.push(if rng.gen_bool(0.5) { "A" } else { "R" }.to_string());
+            }
+            results.shuffle(rng);
+
+            // Everything but the last result needs a condition.
+            let last = results.pop().unwrap();
+            let mut rules: Vec<String> = results
+                .into_iter()
+                .map(|result| {
+                    let which = ['x', 'm', 'a', 's'][rng.gen_range(0..4)];
+                    let op = if rng.gen_bool(0.5) { '<' } else { '>' };
+                    format!("{}{}{}:{}", which, op, rng.gen_range(1..4000), result)
+                })
+                .collect();
+            rules.push(last);
+            workflows.push(format!("{}{{{}}}", name, rules.join(",")));
+        }
+        // The order of the workflows doesn't matter, so don't give away the shape of the tree.
+        workflows.shuffle(rng);
+
+        let parts: Vec<String> = (0..size.div_ceil(2))
+            .map(|_| {
+                let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+                format!("{{x={},m={},a={},s={}}}", x, m, a, s)
+            })
+            .collect();
+
+        format!("{}\n\n{}\n", workflows.join("\n"), parts.join("\n"))
+    }
+}
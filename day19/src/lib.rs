@@ -0,0 +1,439 @@
+use aoc_common::parse::{self, char_token, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::ops::Range;
+
+mod generate;
+
+// A single part is a range containing one value for each rating.
+#[derive(Debug, Clone)]
+pub struct PartRange {
+    pub x: Range<usize>,
+    pub m: Range<usize>,
+    pub a: Range<usize>,
+    pub s: Range<usize>,
+}
+
+#[derive(Debug)]
+enum Which {
+    X,
+    M,
+    A,
+    S,
+}
+use Which::*;
+
+#[derive(Debug)]
+enum LesserOrGreater {
+    Lesser,
+    Greater,
+}
+use LesserOrGreater::*;
+
+#[derive(Debug)]
+struct Condition {
+    which_direction_is_true: LesserOrGreater,
+    which: Which,
+    value: usize,
+}
+
+// Split into a true and false part.
+fn split(condition: &Condition, part: &PartRange) -> (Option<PartRange>, Option<PartRange>) {
+    let PartRange { x, m, a, s } = part;
+    let Condition {
+        which_direction_is_true,
+        which,
+        value,
+    } = condition;
+
+    let replace = |which: &Which, range: Range<usize>| -> PartRange {
+        let x = x.clone();
+        let m = m.clone();
+        let a = a.clone();
+        let s = s.clone();
+        match which {
+            X => PartRange { x: range, m, a, s },
+            M => PartRange { m: range, x, a, s },
+            A => PartRange { a: range, x, m, s },
+            S => PartRange { s: range, x, m, a },
+        }
+    };
+
+    let input_range = match which {
+        X => x,
+        M => m,
+        A => a,
+        S => s,
+    };
+
+    // Split the range at the value.
+    let true_and_false_ranges = {
+        // I feel like I could have tried to combine these in some way, but they're not exact opposites of
+        // each other (because of the possibility of being equal to the value). Oh well, this works.
+        match which_direction_is_true {
+            Lesser => {
+                if input_range.end <= *value {
+                    // Everything is below the value, so we're all true.
+                    (Some(input_range.clone()), None)
+                } else if input_range.start >= *value {
+                    // Everything is above or equal to the value, so we're all false.
+                    (None, Some(input_range.clone()))
+                } else {
+                    // The value's in the middle. Both the checks above imply these
+                    // ranges are non-empty.
+                    let lower = input_range.start..*value;
+                    let upper = *value..input_range.end;
+                    (Some(lower), Some(upper))
+                }
+            }
+            Greater => {
+                if input_range.start > *value {
+                    // Everything is above the value, so we're all true.
+                    (Some(input_range.clone()), None)
+                } else if input_range.end <= *value + 1 {
+                    // Everything is below the value, so we're all false. We have plus 1 here
+                    // because being equal to the value still counts as not being greater.
+                    (None, Some(input_range.clone()))
+                } else {
+                    // The value's in the middle.
+                    let lower = input_range.start..(*value + 1);
+                    let upper = *value + 1..input_range.end;
+                    (Some(upper), Some(lower))
+                }
+            }
+        }
+    };
+
+    let map_range = |r: Option<Range<usize>>| r.map(|r| replace(which, r));
+
+    (
+        map_range(true_and_false_ranges.0),
+        map_range(true_and_false_ranges.1),
+    )
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FinalResult {
+    Accepted,
+    Rejected,
+}
+use FinalResult::*;
+
+#[derive(Eq, PartialEq, Hash, Debug, Clone)]
+struct WorkflowKey(String);
+#[derive(Debug, Clone)]
+enum WorkflowResult {
+    AnotherWorkflow(WorkflowKey),
+    Finished(FinalResult),
+}
+use WorkflowResult::*;
+#[derive(Debug)]
+struct Workflow {
+    conditional_workflows: Vec<(Condition, WorkflowResult)>,
+    if_none_match: WorkflowResult,
+}
+
+#[derive(Debug)]
+pub struct Workflows(HashMap<WorkflowKey, Workflow>);
+
+fn run_one_workflow(workflow: &Workflow, part: PartRange) -> Vec<(PartRange, WorkflowResult)> {
+    let mut results = vec![];
+    let mut in_progress = vec![part];
+
+    for (condition, result) in &workflow.conditional_workflows {
+        let mut new_in_progress = vec![];
+
+        for part in in_progress {
+            let (true_section, false_section) = split(condition, &part);
+
+            if let Some(x) = true_section {
+                results.push((x, result.clone()))
+            };
+
+            if let Some(x) = false_section {
+                new_in_progress.push(x)
+            };
+        }
+
+        in_progress = new_in_progress;
+    }
+
+    // Anything that hasn't been matched so far goes to the final result.
+    for part in in_progress {
+        results.push((part, workflow.if_none_match.clone()));
+    }
+
+    results
+}
+
+pub fn run_workflows(workflows: &Workflows, part: &PartRange) -> Vec<(PartRange, FinalResult)> {
+    let start_key = WorkflowKey("in".to_string());
+
+    let mut finished: Vec<(PartRange, FinalResult)> = vec![];
+    let mut stack: Vec<(PartRange, WorkflowKey)> = vec![(part.clone(), start_key)];
+
+    while let Some((part, workflow_key)) = stack.pop() {
+        let workflow = workflows.0.get(&workflow_key).expect("Workflow not found");
+        let results = run_one_workflow(workflow, part);
+
+        for (part, result) in results {
+            match result {
+                AnotherWorkflow(key) => stack.push((part, key)),
+                Finished(result) => finished.push((part, result)),
+            }
+        }
+    }
+    finished
+}
+
+fn parse_result(s: &str) -> WorkflowResult {
+    match s {
+        "R" => Finished(Rejected),
+        "A" => Finished(Accepted),
+        _ => AnotherWorkflow(WorkflowKey(s.to_string())),
+    }
+}
+
+fn parse_condition(condition: &str) -> Result<Condition, ParseError> {
+    // Conditions look like "a<2006".
+    let which = match condition.chars().next() {
+        Some('x') => X,
+        Some('m') => M,
+        Some('a') => A,
+        Some('s') => S,
+        _ => return Err(ParseError::new(char_token(condition, 0), "x, m, a or s")),
+    };
+    let rest = &condition[1..];
+    let which_direction_is_true = match rest.chars().next() {
+        Some('>') => Greater,
+        Some('<') => Lesser,
+        _ => return Err(ParseError::new(char_token(rest, 0), "> or <")),
+    };
+    let value: usize = parse::number(&rest[1..])?;
+
+    Ok(Condition {
+        which_direction_is_true,
+        which,
+        value,
+    })
+}
+
+// Each workflow is of the form NAME{CONDITIONED, CONDITIONED, ..., CONDITIONED, FINAL}
+// where CONDITIONED is of the form CONDITION:RESULT, and the final one is just a result.
+//
+// As well as the workflow, this returns its name and the RESULTs, so we can check they all exist.
+fn parse_workflow(line: &str) -> Result<(&str, Workflow, Vec<&str>), ParseError> {
+    let line = parse::strip_suffix(line, "}")?;
+    let (name, rest) = parse::split_once(line, "{")?;
+
+    let mut parts: Vec<&str> = rest.split(",").collect();
+    let final_result = parts.pop().unwrap_or_default();
+    let mut results = vec![];
+
+    let mut conditional_workflows = vec![];
+    for conditional_branch in parts {
+        let (condition, result) = parse::split_once(conditional_branch, ":")?;
+        conditional_workflows.push((parse_condition(condition)?, parse_result(result)));
+        results.push(result);
+    }
+    results.push(final_result);
+
+    let workflow = Workflow {
+        conditional_workflows,
+        if_none_match: parse_result(final_result),
+    };
+    Ok((name, workflow, results))
+}
+
+fn parse_part(line: &str) -> Result<PartRange, ParseError> {
+    let part_pieces = parse::strip_prefix(line, "{")?;
+    let part_pieces = parse::strip_suffix(part_pieces, "}")?;
+
+    let mut part = PartRange {
+        x: 0..0,
+        m: 0..0,
+        a: 0..0,
+        s: 0..0,
+    };
+
+    for part_piece in part_pieces.split(",") {
+        let (which, value) = parse::split_once(part_piece, "=")?;
+        let value: usize = parse::number(value)?;
+        let value = value..(value + 1);
+        match which {
+            "x" => part.x = value,
+            "m" => part.m = value,
+            "a" => part.a = value,
+            "s" => part.s = value,
+            _ => return Err(ParseError::new(which, "x, m, a or s")),
+        }
+    }
+
+    Ok(part)
+}
+
+#[derive(Debug)]
+pub struct System {
+    pub workflows: Workflows,
+    pub parts: Vec<PartRange>,
+}
+
+fn parse_system(s: &str) -> Result<System, ParseError> {
+    let (workflows_string, parts_string) = parse::split_once(s, "\n\n")?;
+
+    let mut workflows = Workflows(HashMap::new());
+    // Every workflow that gets sent to, so we can check they all exist.
+    let mut destinations = vec![];
+
+    for line in workflows_string.lines() {
+        let (name, workflow, results) = parse_workflow(line)?;
+        destinations.extend(results);
+        workflows.0.insert(WorkflowKey(name.to_string()), workflow);
+    }
+
+    if !workflows.0.contains_key(&WorkflowKey("in".to_string())) {
+        return Err(ParseError::new(
+            workflows_string,
+            "a workflow called \"in\"",
+        ));
+    }
+    for destination in destinations {
+        let known = matches!(destination, "A" | "R")
+            || workflows
+                .0
+                .contains_key(&WorkflowKey(destination.to_string()));
+        if !known {
+            return Err(ParseError::new(
+                destination,
+                "A, R or the name of a workflow",
+            ));
+        }
+    }
+
+    let parts = parts_string
+        .lines()
+        .map(parse_part)
+        .collect::<Result<_, _>>()?;
+
+    Ok(System { workflows, parts })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_system(s.trim()).map_err(|e| e.locate(s))
+    }
+
+    fn part1(system: &Self::Input) -> Answer {
+        system
+            .parts
+            .iter()
+            .filter(|part| {
+                let results = run_workflows(&system.workflows, part);
+
+                // Because we passed in single ranges, the result here should be a vector of length 1.
+                assert!(results.len() == 1);
+                match results[0].1 {
+                    Accepted => true,
+                    Rejected => false,
+                }
+            })
+            .map(|part| part.x.start + part.m.start + part.a.start + part.s.start)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(system: &Self::Input) -> Answer {
+        let big_range = PartRange {
+            x: 1..4001,
+            m: 1..4001,
+            a: 1..4001,
+            s: 1..4001,
+        };
+        let results = run_workflows(&system.workflows, &big_range);
+        results
+            .iter()
+            .map(|(part, accepted)| match accepted {
+                Rejected => 0,
+                Accepted => part.x.len() * part.m.len() * part.a.len() * part.s.len(),
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+
+    #[test]
+    fn parses_example() {
+        let system = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(system.workflows.0.len(), 11);
+        assert_eq!(system.parts.len(), 5);
+        assert_eq!(system.parts[0].x, 787..788);
+        assert_eq!(system.parts[0].s, 2876..2877);
+    }
+
+    #[test]
+    fn rejects_unknown_workflows() {
+        let e = Day19::parse("in{x<10:foo,A}\n\n{x=1,m=2,a=3,s=4}").unwrap_err();
+        assert_eq!(e.token(), "foo");
+        assert_eq!(e.location().unwrap().column, 9);
+    }
+
+    #[test]
+    fn runs_workflows_on_ranges() {
+        let system = Day19::parse(EXAMPLE).unwrap();
+        let part = PartRange {
+            x: 787..788,
+            m: 2655..2656,
+            a: 1222..1223,
+            s: 2876..2877,
+        };
+        let results = run_workflows(&system.workflows, &part);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].1, Accepted);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part1(&input), Answer::Integer(19114));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part2(&input), Answer::Integer(167409079868000));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day19.txt and answers/day19.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day19>(19);
+    }
+}
@@ -0,0 +1,31 @@
+use crate::Day2;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+impl Generate for Day2 {
+    const SIZE: &'static str = "games";
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        let mut result = String::new();
+        for id in 1..=size {
+            // Up to 20 cubes of a colour, so roughly half the games are possible in part 1.
+            let draws: Vec<String> = (0..rng.gen_range(1..=6))
+                .map(|_| {
+                    let mut colours = ["red", "green", "blue"];
+                    colours.shuffle(rng);
+                    let count = rng.gen_range(1..=3);
+                    colours[..count]
+                        .iter()
+                        .map(|colour| format!("{} {}", rng.gen_range(1..=20), colour))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect();
+            result.push_str(&format!("Game {}: {}\n", id, draws.join("; ")));
+        }
+        result
+    }
+}
@@ -0,0 +1,182 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use tracing::trace;
+
+mod generate;
+
+#[derive(Debug)]
+pub struct Draw {
+    pub red: usize,
+    pub blue: usize,
+    pub green: usize,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: usize,
+    pub draws: Vec<Draw>,
+}
+
+fn parse_draw(s: &str) -> Result<Draw, ParseError> {
+    // Format of a draw is
+    // "N red, M green, K blue"
+    // in some order
+
+    let mut draw = Draw {
+        red: 0,
+        blue: 0,
+        green: 0,
+    };
+
+    let parts: Vec<&str> = s.split(",").map(|s| s.trim()).collect();
+
+    for part in parts {
+        let (n, colour) = parse::split_once(part, " ")?;
+        let n: usize = parse::number(n)?;
+        match colour {
+            "red" => draw.red = n,
+            "green" => draw.green = n,
+            "blue" => draw.blue = n,
+            _ => return Err(ParseError::new(colour, "a colour (red, green or blue)")),
+        }
+    }
+
+    Ok(draw)
+}
+
+fn parse_game(s: &str) -> Result<Game, ParseError> {
+    // Format of the game is:
+    // "Game N: <semicolon separated games>"
+    // where a game is
+    // "N red, M green, K blue"
+    // in some order
+
+    trace!("parsing {}", s);
+    let s = parse::strip_prefix(s, "Game ")?;
+    let (id, draws) = parse::split_once(s, ":")?;
+    let id: usize = parse::number(id)?;
+
+    let draws: Vec<Draw> = draws
+        .split(";")
+        .map(|s| s.trim())
+        .map(parse_draw)
+        .collect::<Result<_, _>>()?;
+
+    Ok(Game { id, draws })
+}
+
+fn game_valid1(game: &Game) -> bool {
+    fn draw_valid(draw: &Draw) -> bool {
+        draw.red <= 12 && draw.green <= 13 && draw.blue <= 14
+    }
+
+    game.draws.iter().all(draw_valid)
+}
+
+fn game_power(game: &Game) -> usize {
+    // The product of the minimum number of cubes in this game.
+
+    fn max_draw(d1: Draw, d2: &Draw) -> Draw {
+        let red = d1.red.max(d2.red);
+        let green = d1.green.max(d2.green);
+        let blue = d1.blue.max(d2.blue);
+        Draw { red, green, blue }
+    }
+
+    // urgh, wanted to use reduce here but I couldn't figure out how to get the borrow checker to like it.
+    let mut draw = Draw {
+        red: 0,
+        green: 0,
+        blue: 0,
+    };
+    for other_draw in &game.draws {
+        draw = max_draw(draw, other_draw);
+    }
+
+    draw.red * draw.green * draw.blue
+}
+
+fn parse_games(s: &str) -> Result<Vec<Game>, ParseError> {
+    s.trim()
+        .lines()
+        .map(parse_game)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(s))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_games(s)
+    }
+
+    fn part1(games: &Self::Input) -> Answer {
+        games
+            .iter()
+            .filter(|g| game_valid1(g))
+            .map(|g| g.id)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(games: &Self::Input) -> Answer {
+        games.iter().map(game_power).sum::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn parses_example() {
+        let games = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(games.len(), 5);
+        assert_eq!(games[0].id, 1);
+        assert_eq!(games[0].draws.len(), 3);
+        assert_eq!(
+            (
+                games[0].draws[0].red,
+                games[0].draws[0].green,
+                games[0].draws[0].blue
+            ),
+            (4, 0, 3)
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_colours() {
+        let e = Day2::parse("Game 1: 3 blue, 4 purple").unwrap_err();
+        assert_eq!(e.token(), "purple");
+        assert_eq!(e.location().unwrap().column, 19);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&input), Answer::Integer(8));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&input), Answer::Integer(2286));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day02.txt and answers/day02.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day2>(2);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::Day20;
+use aoc_common::generate::{distinct_names, Generate};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// A network shaped like the real ones: [counters] binary counters of [bits] flip-flops each, all
+// counting button presses. Each one has a conjunction watching for it to reach its own period,
+// which sends a low pulse (through an inverter) towards rx and resets the counter. Also returns how
+// many presses it takes to get a low pulse to rx, which is the LCM of the periods.
+fn network(rng: &mut StdRng, counters: usize, bits: usize) -> (String, u64) {
+    assert!(bits >= 2, "Counters need at least 2 bits");
+
+    // Periods have the top bit set, so every flip-flop gets used, and they're odd so the lowest
+    // flip-flop is watched too. Keeping them coprime makes the LCM their product, like the real
+    // inputs (which use primes).
+    let mut periods: Vec<u64> = vec![];
+    let mut attempts = 0;
+    while periods.len() < counters {
+        let period = rng.gen_range(1 << (bits - 1)..1 << bits) | 1;
+        if periods.iter().all(|p| gcd(*p, period) == 1) {
+            periods.push(period);
+        }
+        attempts += 1;
+        assert!(
+            attempts < 100_000,
+            "Can't find {} coprime {}-bit periods",
+            counters,
+            bits
+        );
+    }
+
+    let modules = counters * (bits + 2) + 1;
+    // Two letter names, like the real ones, unless there are too many modules to fit.
+    let len = if modules < 300 { 2 } else { 3 };
+    let mut names = distinct_names(rng, modules, len, &["rx"]).into_iter();
+    let mut next_name = || names.next().unwrap();
+
+    let mut lines = vec![];
+    let mut starts = vec![];
+    let last = next_name();
+    for period in &periods {
+        let flip_flops: Vec<String> = (0..bits).map(|_| next_name()).collect();
+        let hub = next_name();
+        let inverter = next_name();
+        starts.push(flip_flops[0].clone());
+
+        // The flip-flops make a ripple counter, with the lowest bit first. The hub resets the
+        // counter by sending low pulses to the bits that are off at the period, which carries
+        // through to everything else - along with the lowest bit, which wraps round to 0.
+        let mut hub_outputs = vec![inverter.clone(), flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs = vec![];
+            if let Some(next) = flip_flops.get(bit + 1) {
+                outputs.push(next.clone());
+            }
+            if period & (1 << bit) != 0 {
+                outputs.push(hub.clone());
+            } else {
+                hub_outputs.push(flip_flop.clone());
+            }
+            outputs.shuffle(rng);
+            lines.push(format!("%{} -> {}", flip_flop, outputs.join(", ")));
+        }
+        hub_outputs.shuffle(rng);
+        lines.push(format!("&{} -> {}", hub, hub_outputs.join(", ")));
+        lines.push(format!("&{} -> {}", inverter, last));
+    }
+    lines.push(format!("&{} -> rx", last));
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    lines.shuffle(rng);
+
+    let presses = periods.iter().product();
+    (lines.join("\n") + "\n", presses)
+}
+
+impl Generate for Day20 {
+    const SIZE: &'static str = "counters feeding into rx";
+    const DEFAULT_SIZE: usize = 4;
+    // The periods have to be coprime, and there are only about 250 primes with 12 bits.
+    const MAX_SIZE: usize = 200;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        network(rng, size.max(1), 12).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solution::{Answer, Solution};
+    use rand::SeedableRng;
+
+    #[test]
+    fn part2_finds_the_lcm_of_the_periods() {
+        for seed in 0..10 {
+            let (s, presses) = network(&mut StdRng::seed_from_u64(seed), 4, 12);
+            let input = Day20::parse(&s).unwrap();
+            assert_eq!(Day20::part2(&input), Answer::from(presses));
+        }
+    }
+
+    #[test]
+    fn rx_gets_a_low_pulse_after_the_lcm_of_the_periods() {
+        // Small enough to just press the button until it happens.
+        for seed in 0..10 {
+            let (s, presses) = network(&mut StdRng::seed_from_u64(seed), 3, 4);
+            let modules = Day20::parse(&s).unwrap();
+            assert_eq!(crate::tests::brute_force(&modules, 10_000), Some(presses));
+        }
+    }
+}
@@ -0,0 +1,802 @@
+use aoc_common::maths::crt;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::mem;
+use tracing::{debug, info};
+
+mod generate;
+
+/*
+Notes:
+
+% = flip-flop, stores a state. Receiving a low pulse means "switch state".
+& = an and.
+broadcaster = just resends pulse
+button = when it's pressed, sends a low pulse to the broadcaster
+*/
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Pulse {
+    High,
+    Low,
+}
+use Pulse::*;
+
+#[derive(Debug, Clone, Copy)]
+enum OnOrOff {
+    On,
+    Off,
+}
+use OnOrOff::*;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Ord, PartialOrd)]
+struct ModuleKey(String);
+
+#[derive(Debug, Clone)]
+enum ModuleType {
+    FlipFlop {
+        internal_state: OnOrOff,
+    },
+    Nand {
+        input_component_to_last_known_pulse: HashMap<ModuleKey, Pulse>,
+        number_of_low_pulses: usize,
+    },
+    Broadcaster,
+    Sink,
+}
+use ModuleType::*;
+
+impl ModuleType {
+    fn to_compact_state(&self) -> usize {
+        match self {
+            FlipFlop { internal_state } => match internal_state {
+                Off => 0,
+                On => 1,
+            },
+            Nand {
+                input_component_to_last_known_pulse,
+                number_of_low_pulses: _,
+            } => {
+                let mut keys: Vec<ModuleKey> = input_component_to_last_known_pulse
+                    .keys()
+                    .cloned()
+                    .collect();
+                keys.sort();
+
+                let mut total = 0;
+                for (i, key) in keys.iter().enumerate() {
+                    let pulse = input_component_to_last_known_pulse.get(key).unwrap();
+                    total += (2usize.pow(i as u32))
+                        * (match pulse {
+                            High => 1,
+                            Low => 0,
+                        })
+                }
+                total
+            }
+            Broadcaster => 0,
+            Sink => 0,
+        }
+    }
+
+    fn process_pulse(&mut self, module_key: &ModuleKey, pulse: &Pulse) -> Option<Pulse> {
+        match self {
+            FlipFlop { internal_state } => match pulse {
+                /* Flip-flop - this ignores high pulses, and makes low pulses switch the internal state. */
+                High => None,
+                Low => {
+                    let new_internal_state = match internal_state {
+                        On => Off,
+                        Off => On,
+                    };
+                    let _ = mem::replace(internal_state, new_internal_state);
+                    match new_internal_state {
+                        On => Some(High),
+                        Off => Some(Low),
+                    }
+                }
+            },
+            Nand {
+                input_component_to_last_known_pulse,
+                number_of_low_pulses,
+            } => {
+                /* This is essentially a NAND gate - remembers the old pulse from all of its inputs. */
+                let old_pulse = input_component_to_last_known_pulse.get(module_key).expect("BUG - didn't previously see a value from this component, should have been initialised to empty");
+                if old_pulse != pulse {
+                    // Update our internal state.
+                    let _old_state =
+                        input_component_to_last_known_pulse.insert(module_key.clone(), *pulse);
+                    match pulse {
+                        High => *number_of_low_pulses -= 1,
+                        Low => *number_of_low_pulses += 1,
+                    };
+                }
+
+                if *number_of_low_pulses == 0 {
+                    Some(Low)
+                } else {
+                    Some(High)
+                }
+            }
+            Broadcaster => Some(*pulse),
+            Sink => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Module {
+    type_: ModuleType,
+    outputs: Vec<ModuleKey>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Modules {
+    modules: HashMap<ModuleKey, Module>,
+    // (module0, module1, pulse, depth) means that module0 sent pulse to module1, and it's the
+    // [depth]th pulse in a chain of them starting from the button.
+    pulse_queue: VecDeque<(ModuleKey, ModuleKey, Pulse, usize)>,
+    total_high_pulses: usize,
+    total_low_pulses: usize,
+}
+
+impl Modules {
+    fn compact_state(&self) -> String {
+        assert!(self.pulse_queue.is_empty());
+
+        self.modules
+            .iter()
+            .map(|(module_key, module)| {
+                let s1 = &module_key.0;
+                let s2 = module.type_.to_compact_state().to_string();
+                s2 + s1 + ";"
+            })
+            .collect()
+    }
+
+    fn process_all_pulses(&mut self) -> Vec<(ModuleKey, ModuleKey, Pulse, usize)> {
+        let mut result = vec![];
+
+        while !self.pulse_queue.is_empty() {
+            let (input_key, output_key, pulse, depth) = self.pulse_queue.pop_front().unwrap();
+            result.push((input_key.clone(), output_key.clone(), pulse, depth));
+
+            match pulse {
+                High => self.total_high_pulses += 1,
+                Low => self.total_low_pulses += 1,
+            };
+            let module = self.modules.get_mut(&output_key).unwrap();
+            let pulse = module.type_.process_pulse(&input_key, &pulse);
+            if let Some(pulse) = pulse {
+                for key in &module.outputs {
+                    self.pulse_queue
+                        .push_back((output_key.clone(), key.clone(), pulse, depth + 1));
+                }
+            }
+        }
+        result
+    }
+
+    fn press_button(&mut self) -> Vec<(ModuleKey, ModuleKey, Pulse, usize)> {
+        // Find the broadcaster module.
+        let key = ModuleKey("broadcaster".to_string());
+        let button_key = ModuleKey("button".to_string());
+
+        assert!(self.pulse_queue.is_empty());
+        self.pulse_queue.push_back((button_key, key, Low, 0));
+        self.process_all_pulses()
+    }
+
+    fn create() -> Self {
+        Modules {
+            modules: HashMap::new(),
+            pulse_queue: VecDeque::new(),
+            total_high_pulses: 0,
+            total_low_pulses: 0,
+        }
+    }
+
+    fn add_module(&mut self, line: &str) -> Result<(), ParseError> {
+        let (target, outputs) = parse::split_once(line, " -> ")?;
+
+        let (module_key, type_): (ModuleKey, ModuleType) = {
+            match target.chars().nth(0) {
+                Some('%') => {
+                    // This is a flip-flop.
+                    let name = target[1..].to_string();
+                    let type_ = FlipFlop {
+                        internal_state: Off,
+                    };
+                    let name = ModuleKey(name);
+                    (name, type_)
+                }
+                Some('&') => {
+                    // This is a NAND.
+                    let name = target[1..].to_string();
+
+                    // We don't properly initialise the dependencies in NAND gates, this gets done in finalise.
+                    let type_ = Nand {
+                        input_component_to_last_known_pulse: HashMap::new(),
+                        number_of_low_pulses: 0,
+                    };
+                    let name = ModuleKey(name);
+                    (name, type_)
+                }
+                _ => {
+                    if target == "broadcaster" {
+                        (ModuleKey(target.to_string()), Broadcaster)
+                    } else {
+                        return Err(ParseError::new(
+                            target,
+                            "a flip-flop (%name), a conjunction (&name) or broadcaster",
+                        ));
+                    }
+                }
+            }
+        };
+
+        let outputs: Vec<ModuleKey> = outputs
+            .split(", ")
+            .map(|s| ModuleKey(s.to_string()))
+            .collect();
+
+        let module = Module { type_, outputs };
+        self.modules.insert(module_key, module);
+        Ok(())
+    }
+
+    fn finalise(&mut self) {
+        // Bit of a hack - we want to fix up some state after we know we've added everything. This includes:
+        // - Identifying any sink nodes.
+        // - Fixing up NAND gates.
+
+        let module_keys: Vec<ModuleKey> = self.modules.keys().cloned().collect();
+        let mut input_to_output_keys: HashMap<ModuleKey, Vec<ModuleKey>> = HashMap::new();
+        for key in module_keys {
+            let output_keys = self.modules.get(&key).unwrap().outputs.clone();
+            for output_key in output_keys {
+                let v = input_to_output_keys.entry(key.clone()).or_insert(vec![]);
+                v.push(output_key);
+            }
+        }
+
+        // Identify and add any sink nodes.
+        for output_keys in input_to_output_keys.values() {
+            for output_key in output_keys {
+                if !self.modules.contains_key(output_key) {
+                    let module = Module {
+                        type_: Sink,
+                        outputs: vec![],
+                    };
+                    self.modules.insert(output_key.clone(), module);
+                }
+            }
+        }
+
+        // Fix up state of NAND keys.
+        for (input_key, output_keys) in input_to_output_keys.into_iter() {
+            for output_key in output_keys {
+                let output_module = self.modules.get_mut(&output_key).unwrap();
+                if let Nand {
+                    input_component_to_last_known_pulse,
+                    number_of_low_pulses,
+                } = &mut output_module.type_
+                {
+                    input_component_to_last_known_pulse.insert(input_key.clone(), Low);
+                    *number_of_low_pulses += 1;
+                }
+            }
+        }
+    }
+
+    fn strip_to_dependency_tree_from_node(&mut self, target_key: &ModuleKey) {
+        let module_keys: Vec<ModuleKey> = self.modules.keys().cloned().collect();
+        let mut output_to_input_keys: HashMap<ModuleKey, Vec<ModuleKey>> = HashMap::new();
+
+        for key in module_keys {
+            let output_keys = self.modules.get(&key).unwrap().outputs.clone();
+            for output_key in output_keys {
+                let v = output_to_input_keys
+                    .entry(output_key.clone())
+                    .or_insert(vec![]);
+                v.push(key.clone());
+            }
+        }
+
+        let find_component = |links: &HashMap<ModuleKey, Vec<ModuleKey>>| -> HashSet<ModuleKey> {
+            let mut result = HashSet::new();
+            let mut process_queue = vec![target_key.clone()];
+
+            while let Some(v) = process_queue.pop() {
+                let next_ = links.get(&v);
+                result.insert(v.clone());
+
+                match next_ {
+                    None => (),
+                    Some(next_) => {
+                        for n in next_ {
+                            if !result.contains(n) {
+                                process_queue.push(n.clone());
+                            }
+                        }
+                    }
+                }
+            }
+
+            result
+        };
+
+        let in_ = find_component(&output_to_input_keys);
+
+        // Strip out everything that's not in that connected component.
+        let old_modules = std::mem::take(&mut self.modules);
+        self.modules = old_modules
+            .into_iter()
+            .filter_map(|(module_key, module)| {
+                if in_.contains(&module_key) {
+                    Some((module_key, module))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        for module in self.modules.values_mut() {
+            module.outputs = module
+                .outputs
+                .iter()
+                .filter(|key| in_.contains(key))
+                .cloned()
+                .collect();
+        }
+    }
+}
+
+fn parse(s: &str) -> Result<Modules, ParseError> {
+    let mut modules = Modules::create();
+    for line in s.trim().lines() {
+        modules.add_module(line).map_err(|e| e.locate(s))?;
+    }
+    if !modules
+        .modules
+        .contains_key(&ModuleKey("broadcaster".to_string()))
+    {
+        return Err(ParseError::new(parse::end_of(s), "a broadcaster module").locate(s));
+    }
+    modules.finalise();
+    Ok(modules)
+}
+
+// When one of the inputs to the conjunction in front of rx is sending it high pulses during a
+// button press, as ranges of pulse depths. (start, end) means it sent a high pulse [start] pulses
+// after the button was pressed, and then nothing but high pulses until a low one at [end]. The
+// first range starts at 0 if it was already high from the last press, and the last one ends at
+// usize::MAX if it's still high at the end of this one.
+type Windows = Vec<(usize, usize)>;
+
+fn windows(mut last_pulse: Pulse, pulses: &[(Pulse, usize)]) -> Windows {
+    let mut result = vec![];
+    let mut start = 0;
+    for (pulse, depth) in pulses {
+        match (last_pulse, pulse) {
+            (Low, High) => start = *depth,
+            (High, Low) => result.push((start, *depth)),
+            _ => (),
+        }
+        last_pulse = *pulse;
+    }
+    if last_pulse == High {
+        result.push((start, usize::MAX));
+    }
+    result
+}
+
+// When two inputs are both high. Pulses are handled in order of depth, so the conjunction sees both
+// inputs high at once if there's a depth where one goes high while the other is high already. If
+// one goes high at the same depth another goes low, which comes first depends on how the rest of
+// the network's wired up, so to be safe that doesn't count.
+fn overlap(a: &Windows, b: &Windows) -> Windows {
+    let mut result = vec![];
+    for (start1, end1) in a {
+        for (start2, end2) in b {
+            let start = *start1.max(start2);
+            let end = *end1.min(end2);
+            if start < end {
+                result.push((start, end));
+            }
+        }
+    }
+    result
+}
+
+// How one of the inputs to the conjunction in front of rx behaves, press by press. After the first
+// [presses_before_cycle] presses, it repeats every [cycle_length] presses.
+#[derive(Debug)]
+struct Subnetwork {
+    presses_before_cycle: usize,
+    cycle_length: usize,
+    // The windows for each press, up to the end of the first time round the cycle, for the presses
+    // where there are any.
+    windows: BTreeMap<usize, Windows>,
+}
+
+impl Subnetwork {
+    fn windows(&self, press: usize) -> Option<&Windows> {
+        let first_cycle_end = self.presses_before_cycle + self.cycle_length;
+        let press = if press <= first_cycle_end {
+            press
+        } else {
+            self.presses_before_cycle
+                + 1
+                + (press - self.presses_before_cycle - 1) % self.cycle_length
+        };
+        self.windows.get(&press)
+    }
+}
+
+fn analyse_subnetwork(
+    mut modules: Modules,
+    target_key: &ModuleKey,
+    conjunction_key: &ModuleKey,
+) -> Result<Subnetwork, &'static str> {
+    // [modules] only has what [target_key] depends on, so add back the wire to the conjunction.
+    modules.modules.insert(
+        conjunction_key.clone(),
+        Module {
+            type_: Sink,
+            outputs: vec![],
+        },
+    );
+    let target = modules.modules.get_mut(target_key).unwrap();
+    target.outputs.push(conjunction_key.clone());
+
+    // If the button doesn't reach this input at all, it never sends anything.
+    if !modules
+        .modules
+        .contains_key(&ModuleKey("broadcaster".to_string()))
+    {
+        return Ok(Subnetwork {
+            presses_before_cycle: 0,
+            cycle_length: 1,
+            windows: BTreeMap::new(),
+        });
+    }
+
+    // The state of the sub-network doesn't quite say what the last pulse to the conjunction was -
+    // at the start, nothing's been sent yet - so keep track of that too.
+    let mut last_pulse = Low;
+    let mut state = HashMap::<(String, Pulse), usize>::new();
+    state.insert((modules.compact_state(), last_pulse), 0);
+    let mut windows_by_press = BTreeMap::new();
+
+    for press in 1..=2_000_000 {
+        let pulses: Vec<(Pulse, usize)> = modules
+            .press_button()
+            .into_iter()
+            .filter(|(input_key, output_key, _pulse, _depth)| {
+                input_key == target_key && output_key == conjunction_key
+            })
+            .map(|(_input_key, _output_key, pulse, depth)| (pulse, depth))
+            .collect();
+        let windows = windows(last_pulse, &pulses);
+        if let Some((pulse, _depth)) = pulses.last() {
+            last_pulse = *pulse;
+        }
+        if !windows.is_empty() {
+            debug!(
+                "{}: high during button press {} at depths {:?}",
+                target_key.0, press, windows
+            );
+            windows_by_press.insert(press, windows);
+        }
+
+        if let Some(old_press) = state.insert((modules.compact_state(), last_pulse), press) {
+            info!(
+                "{}: cycle in the state, first seen after button press {} and again after {}",
+                target_key.0, old_press, press
+            );
+            return Ok(Subnetwork {
+                presses_before_cycle: old_press,
+                cycle_length: press - old_press,
+                windows: windows_by_press,
+            });
+        }
+
+        if press % 1_000_000 == 0 {
+            debug!("{}: {} button presses", target_key.0, press);
+        }
+    }
+    Err("one of the sub-networks feeding into rx didn't cycle within 2,000,000 button presses")
+}
+
+// The first button press where all the sub-networks are high at the same time, or [None] if they
+// never are.
+fn first_press_all_high(subnetworks: &[Subnetwork]) -> Option<BigInt> {
+    // Until every sub-network has got into its cycle, just check each press.
+    let all_cycling = subnetworks
+        .iter()
+        .map(|s| s.presses_before_cycle)
+        .max()
+        .unwrap_or(0);
+    for press in 1..=all_cycling {
+        let windows = subnetworks
+            .iter()
+            .try_fold(vec![(0, usize::MAX)], |windows, s| {
+                s.windows(press).map(|w| overlap(&windows, w))
+            });
+        if windows.is_some_and(|w| !w.is_empty()) {
+            return Some(press.into());
+        }
+    }
+
+    // After that, each sub-network is high on presses which are one of a few remainders mod its
+    // cycle length. Like the ghosts on day 8, combine them one at a time with the Chinese Remainder
+    // Theorem, but only where they're high at the same time during the press as well.
+    let mut solutions: Vec<(BigInt, BigInt, Windows)> =
+        vec![(BigInt::zero(), BigInt::one(), vec![(0, usize::MAX)])];
+    for subnetwork in subnetworks {
+        let cycle_length = BigInt::from(subnetwork.cycle_length);
+        let in_cycle = subnetwork
+            .windows
+            .range(subnetwork.presses_before_cycle + 1..);
+        let mut next_solutions = vec![];
+        for (a, m, windows) in &solutions {
+            for (press, press_windows) in in_cycle.clone() {
+                let windows = overlap(windows, press_windows);
+                if windows.is_empty() {
+                    continue;
+                }
+                if let Some((b, n)) = crt(a, m, &BigInt::from(*press), &cycle_length) {
+                    next_solutions.push((b, n, windows));
+                }
+            }
+        }
+        solutions = next_solutions;
+    }
+
+    // The first press after [all_cycling] with each remainder.
+    let start = BigInt::from(all_cycling + 1);
+    solutions
+        .into_iter()
+        .map(|(a, m, _windows)| {
+            if a >= start {
+                a
+            } else {
+                &a + (&start - &a).div_ceil(&m) * m
+            }
+        })
+        .min()
+}
+
+// How many button presses it takes to send a low pulse to rx, if it ever happens.
+pub fn solve_part2(modules: &Modules) -> Result<Option<BigInt>, &'static str> {
+    // Originally I plotted the structure in Graphviz, and found that there are 4 strongly connected
+    // components that end up feeding into a conjunction, which feeds "rx". The conjunction only sends
+    // a low pulse when all its inputs last sent it a high pulse, so they all need to send a high pulse
+    // on the same button press. This finds that structure, then finds how many presses it takes for
+    // each of the sub-networks to cycle, and which of those presses it sends a high pulse on. In the
+    // real input, each of them only sends a high pulse on the last press of its cycle, like the ghosts
+    // problem on day 8, so the answer is the LCM of the cycle lengths.
+    let rx = ModuleKey("rx".to_string());
+    let feeding_rx: Vec<&ModuleKey> = modules
+        .modules
+        .iter()
+        .filter(|(_key, module)| module.outputs.contains(&rx))
+        .map(|(key, _module)| key)
+        .collect();
+    let [conjunction_key] = feeding_rx[..] else {
+        return Err("rx needs to be fed by exactly one module");
+    };
+    let Nand {
+        input_component_to_last_known_pulse,
+        ..
+    } = &modules.modules[conjunction_key].type_
+    else {
+        return Err("rx needs to be fed by a conjunction");
+    };
+    let mut target_keys: Vec<&ModuleKey> = input_component_to_last_known_pulse.keys().collect();
+    target_keys.sort();
+
+    // The cycles are only independent if the sub-networks are - the only module they can share is
+    // the broadcaster.
+    let mut subnetworks = vec![];
+    let mut seen = HashSet::new();
+    for target_key in target_keys {
+        let mut subnetwork = modules.clone();
+        subnetwork.strip_to_dependency_tree_from_node(target_key);
+        for key in subnetwork.modules.keys() {
+            if key == conjunction_key {
+                return Err("the conjunction feeding rx feeds back into itself");
+            }
+            if key.0 != "broadcaster" && !seen.insert(key.clone()) {
+                return Err("the sub-networks feeding into rx aren't independent");
+            }
+        }
+        subnetworks.push(analyse_subnetwork(subnetwork, target_key, conjunction_key)?);
+    }
+
+    Ok(first_press_all_high(&subnetworks))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Modules;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(modules: &Self::Input) -> Answer {
+        /* Create the modules, press the button 1000 times, count how many low/high pulses there have been. */
+        let mut modules = modules.clone();
+
+        for _ in 0..1_000 {
+            modules.press_button();
+        }
+        (modules.total_high_pulses * modules.total_low_pulses).into()
+    }
+
+    fn part2(modules: &Self::Input) -> Answer {
+        match solve_part2(modules) {
+            Ok(Some(presses)) => presses.into(),
+            Ok(None) => Answer::Failed("rx never gets a low pulse".to_string()),
+            Err(e) => Answer::Failed(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+";
+    const EXAMPLE2: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+    #[test]
+    fn parses_example() {
+        let modules = Day20::parse(EXAMPLE2).unwrap();
+        // The five modules in the input, plus the "output" sink.
+        assert_eq!(modules.modules.len(), 6);
+        assert!(matches!(
+            modules.modules[&ModuleKey("output".to_string())].type_,
+            Sink
+        ));
+    }
+
+    #[test]
+    fn rejects_a_missing_broadcaster() {
+        let e = Day20::parse("%a -> b\n%b -> a\n").unwrap_err();
+        assert_eq!(e.expected(), "a broadcaster module");
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day20::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day20::part1(&input), Answer::Integer(32000000));
+    }
+
+    #[test]
+    fn part1_example_with_an_output() {
+        let input = Day20::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day20::part1(&input), Answer::Integer(11687500));
+    }
+
+    // The number of button presses until rx gets a low pulse, the slow way.
+    pub fn brute_force(modules: &Modules, limit: u64) -> Option<u64> {
+        let mut modules = modules.clone();
+        let rx = ModuleKey("rx".to_string());
+        (1..=limit).find(|_press| {
+            let pulses = modules.press_button();
+            pulses
+                .iter()
+                .any(|(_from, to, pulse, _depth)| *to == rx && *pulse == Low)
+        })
+    }
+
+    // Checks part 2 against pressing the button, where it's quick enough to do that.
+    fn check_part2(s: &str) -> Option<u64> {
+        let modules = Day20::parse(s).unwrap();
+        let presses = solve_part2(&modules)
+            .unwrap()
+            .map(|presses| u64::try_from(presses).unwrap());
+        assert_eq!(presses, brute_force(&modules, 10_000));
+        presses
+    }
+
+    #[test]
+    fn part2_needs_the_pulses_at_the_same_time() {
+        // Both inputs to the conjunction are high at some point during press 2, but the first goes
+        // low before the second goes high, so rx doesn't get a low pulse until press 3.
+        let s = "\
+broadcaster -> a, b
+%a -> con
+%b -> c
+&c -> con
+&con -> rx
+";
+        assert_eq!(check_part2(s), Some(3));
+    }
+
+    #[test]
+    fn part2_for_cycles_which_are_high_more_than_once() {
+        // a, b and c count in binary, and the first input is high when a and c are both on (on
+        // presses 5 and 7, mod 8), and the second input when d is on (every other press after the
+        // first).
+        let s = "\
+broadcaster -> a, d
+%a -> b, x
+%b -> c
+%c -> x
+&x -> y
+&y -> con
+%d -> e
+&e -> f
+&f -> con
+&con -> rx
+";
+        check_part2(s);
+    }
+
+    #[test]
+    fn part2_can_fail() {
+        let modules = Day20::parse("broadcaster -> a\n%a -> b\n").unwrap();
+        assert_eq!(
+            Day20::part2(&modules),
+            Answer::Failed("rx needs to be fed by exactly one module".to_string())
+        );
+
+        // Both inputs depend on a, so their cycles can't be combined independently.
+        let s = "\
+broadcaster -> a
+%a -> x, y
+&x -> con
+&y -> con
+&con -> rx
+";
+        let modules = Day20::parse(s).unwrap();
+        assert_eq!(
+            Day20::part2(&modules),
+            Answer::Failed("the sub-networks feeding into rx aren't independent".to_string())
+        );
+
+        // Nothing ever sends a pulse to b, so it never goes high.
+        let s = "\
+broadcaster -> a
+%a -> con
+%b -> con
+&con -> rx
+";
+        let modules = Day20::parse(s).unwrap();
+        assert_eq!(solve_part2(&modules), Ok(None));
+        assert_eq!(brute_force(&modules, 1_000), None);
+        assert_eq!(
+            Day20::part2(&modules),
+            Answer::Failed("rx never gets a low pulse".to_string())
+        );
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day20.txt and answers/day20.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day20>(20);
+    }
+}
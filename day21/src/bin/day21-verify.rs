@@ -0,0 +1,98 @@
+// Checks part 2 against a plain search of the garden tiled a few times in each direction:
+//
+//     cargo run --release -p day21 --bin day21-verify -- [--tiles K] [--symmetry] inputs/day21.txt
+//
+// By default this checks [solve_part2_by_extrapolating] - what [solve_part2] does for the real
+// number of steps - from the fewest steps it can extrapolate for, over a whole period, and works out
+// how many tiles that needs. --from A and --to B check from A to B steps instead, inclusive. B still
+// has to fit inside the tiles.
+//
+// --symmetry checks my original [solve_part2_by_symmetry] instead, which only works on gardens like
+// the real inputs, from 0 steps by default.
+
+use aoc_common::parse::or_exit;
+use aoc_common::solution::Solution;
+use day21::{solve_part2_by_extrapolating, solve_part2_by_symmetry, verify, Day21};
+use std::env;
+use std::process;
+
+fn main() {
+    let usage = || -> ! {
+        eprintln!("usage: day21-verify [--tiles K] [--from A] [--to B] [--symmetry] [INPUT]");
+        eprintln!("K is how many times to tile the garden in each direction, and has to be odd.");
+        eprintln!("A and B are the fewest and most steps to check.");
+        process::exit(2);
+    };
+
+    let mut tiles = None;
+    let mut from = None;
+    let mut to = None;
+    let mut symmetry = false;
+    let mut path = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--tiles" {
+            tiles = match args.next().and_then(|k| k.parse().ok()) {
+                Some(k) if k % 2 == 1 => Some(k),
+                _ => usage(),
+            };
+        } else if arg == "--from" {
+            from = match args.next().and_then(|a| a.parse().ok()) {
+                Some(a) => Some(a),
+                None => usage(),
+            };
+        } else if arg == "--to" {
+            to = match args.next().and_then(|b| b.parse().ok()) {
+                Some(b) => Some(b),
+                None => usage(),
+            };
+        } else if arg == "--symmetry" {
+            symmetry = true;
+        } else if path.is_none() {
+            path = Some(arg);
+        } else {
+            usage();
+        }
+    }
+
+    let input = aoc_common::input::read_input(21, path.as_deref()).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    });
+    let garden = or_exit(Day21::parse(&input));
+
+    let from = from.unwrap_or(match symmetry {
+        true => 0,
+        false => garden.first_extrapolated(),
+    });
+    let tiles = tiles.unwrap_or_else(|| garden.tiles_for(to.unwrap_or(from + garden.period())));
+    let most = garden.steps_within(tiles);
+    let to = to.unwrap_or(most);
+    if to > most {
+        eprintln!(
+            "error: {tiles}x{tiles} tiles only fit {most} steps, not {to} - try more --tiles"
+        );
+        process::exit(2);
+    }
+    if from > to {
+        eprintln!("error: {from} steps is more than {to} - try more --tiles, or a lower --from");
+        process::exit(2);
+    }
+
+    let result = match symmetry {
+        true => verify(&garden, tiles, from..to + 1, |garden, steps| {
+            Ok(solve_part2_by_symmetry(garden, steps) as u64)
+        }),
+        false => verify(&garden, tiles, from..to + 1, solve_part2_by_extrapolating),
+    };
+    match result {
+        Ok(()) => println!("All good from {from} to {to} steps, on {tiles}x{tiles} tiles"),
+        Err(mismatch) => {
+            println!(
+                "Wrong after {} steps: expected {}, got {:?}",
+                mismatch.steps, mismatch.expected, mismatch.got
+            );
+            process::exit(1);
+        }
+    }
+}
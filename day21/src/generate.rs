@@ -0,0 +1,57 @@
+use crate::Day21;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day21 {
+    const SIZE: &'static str = "rows and columns (odd)";
+    const DEFAULT_SIZE: usize = 131;
+    const DENSITY: Option<&'static str> = Some("a rock in each cell");
+    const DEFAULT_DENSITY: f64 = 0.15;
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String {
+        // Part 2 needs the same things as the real inputs: a square with an odd side, the start in
+        // the middle, and no rocks in the middle row and column or around the edge.
+        let size = size.max(1) | 1;
+        let middle = size / 2;
+        let mut result = String::new();
+        for row in 0..size {
+            result.extend((0..size).map(|col| {
+                let clear = [row, col].iter().any(|x| [0, middle, size - 1].contains(x));
+                if row == middle && col == middle {
+                    'S'
+                } else if !clear && rng.gen_bool(density) {
+                    '#'
+                } else {
+                    '.'
+                }
+            }));
+            result.push('\n');
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solution::Solution;
+    use rand::SeedableRng;
+
+    #[test]
+    fn both_ways_of_doing_part2_agree() {
+        // These have everything [solve_part2_by_symmetry] needs, so the two ways of doing part 2
+        // should agree.
+        for seed in 0..10 {
+            let s = Day21::generate(&mut StdRng::seed_from_u64(seed), 11, 0.15);
+            let input = Day21::parse(&s).unwrap();
+            for steps in [100, 1_000, 5_000] {
+                assert_eq!(
+                    crate::solve_part2(&input, steps),
+                    Ok(crate::solve_part2_by_symmetry(&input, steps) as u64),
+                    "seed {seed}, {steps} steps"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,709 @@
+use aoc_common::grid::{Grid, Position};
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use num_integer::Integer;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::ops::Range;
+
+mod generate;
+
+#[derive(Debug)]
+pub struct Garden {
+    // true for the garden plots, false for the rocks.
+    plots: Grid<bool>,
+    adjacency_list: HashMap<Position, Vec<Position>>,
+    elf_position: Position,
+    height: usize,
+    width: usize,
+}
+
+fn parse(s: &str) -> Result<Garden, ParseError> {
+    let chars = Grid::parse(s, "'.', '#' or 'S'", |c| {
+        matches!(c, '.' | '#' | 'S').then_some(c)
+    })
+    .map_err(|e| e.locate(s))?;
+    let elf_position = chars
+        .find(|c| *c == 'S')
+        .ok_or_else(|| ParseError::new(parse::end_of(s), "a starting square 'S'").locate(s))?;
+
+    // true for the garden plots, false for the rocks.
+    let plots = chars.map(|c| *c != '#');
+    Ok(Garden::new(plots, elf_position))
+}
+
+impl Garden {
+    fn new(plots: Grid<bool>, elf_position: Position) -> Garden {
+        let mut adjacency_list = HashMap::new();
+
+        for (position, is_plot) in plots.iter() {
+            if *is_plot {
+                let neighbours = plots.neighbours(position).filter(|p| plots[*p]).collect();
+                adjacency_list.insert(position, neighbours);
+            }
+        }
+
+        Garden {
+            height: plots.height(),
+            width: plots.width(),
+            plots,
+            adjacency_list,
+            elf_position,
+        }
+    }
+
+    // The garden repeated [tiles] times in each direction, with the elf in the middle copy. [tiles]
+    // has to be odd.
+    pub fn tiled(&self, tiles: usize) -> Garden {
+        let (plots, elf_position) = tile(self, tiles);
+        Garden::new(plots, elf_position)
+    }
+
+    // The most steps the elf can take in the garden tiled [tiles] times without being able to walk
+    // off the edge of it - so anything up to this many steps is the same as on the infinite garden.
+    pub fn steps_within(&self, tiles: usize) -> usize {
+        let Position(i, j) = self.elf_position;
+        let middle = tiles / 2;
+        [
+            i + middle * self.height,
+            self.height - 1 - i + middle * self.height,
+            j + middle * self.width,
+            self.width - 1 - j + middle * self.width,
+        ]
+        .into_iter()
+        .min()
+        .unwrap()
+    }
+
+    // The fewest tiles that fit [steps] steps, see [Garden::steps_within].
+    pub fn tiles_for(&self, steps: usize) -> usize {
+        (0..)
+            .map(|k| 2 * k + 1)
+            .find(|tiles| self.steps_within(*tiles) >= steps)
+            .unwrap()
+    }
+
+    // How many steps further out the edge of the reachable region looks the same again, one more
+    // tile out in every direction - see [solve_part2].
+    pub fn period(&self) -> usize {
+        let period = self.height.lcm(&self.width);
+        match period % 2 {
+            0 => period,
+            _ => 2 * period,
+        }
+    }
+
+    // The fewest steps [solve_part2_by_extrapolating] can possibly manage. It needs more if the
+    // counts take a while to settle down.
+    pub fn first_extrapolated(&self) -> usize {
+        (FIRST_PERIODS + 1) * self.period()
+    }
+}
+
+fn dijkstra(grid: &Garden, point: &Position) -> HashMap<Position, usize> {
+    // Do Dijkstra from a starting point to every other point.
+    #[derive(PartialEq, Eq)]
+    struct Key {
+        cell: Position,
+        distance: usize,
+    }
+
+    impl PartialOrd for Key {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Key {
+        fn cmp(&self, other: &Self) -> Ordering {
+            // Reverse sorting, because [BinaryHeap] is a max-heap.
+            other.distance.cmp(&self.distance)
+        }
+    }
+
+    let mut best_distances = HashMap::new();
+    let mut process_queue: BinaryHeap<Key> = BinaryHeap::new();
+
+    process_queue.push(Key {
+        cell: *point,
+        distance: 0,
+    });
+
+    while !process_queue.is_empty() {
+        let Key { cell, distance } = process_queue.pop().unwrap();
+
+        if !best_distances.contains_key(&cell) {
+            for neighbour in &grid.adjacency_list[&cell] {
+                if !best_distances.contains_key(neighbour) {
+                    process_queue.push(Key {
+                        cell: *neighbour,
+                        distance: distance + 1,
+                    });
+                }
+            }
+            best_distances.insert(cell, distance);
+        }
+    }
+    best_distances
+}
+
+pub fn solve_part1(grid: &Garden, target_distance: usize) -> usize {
+    // Count how many squares are exactly 64 steps away.
+    // We can do this with Dijkstra - find the distance from the elf to any other cell.
+    // Then exactly 64 steps away <--> exactly N steps away for some even N, N <= 64.
+    //
+    // (Why? Any cell is either an even or odd number of steps away - think of a chessboard colouring. And
+    // if we're N steps away for N < 64, we're exactly 64 steps away by doing some redundant moves at the
+    // start.)
+
+    // Count how many spaces are an even distance away, and within 64 steps.
+    dijkstra(grid, &grid.elf_position)
+        .into_iter()
+        .filter_map(|(space, distance)| {
+            if distance % 2 == target_distance % 2 && distance <= target_distance {
+                Some(space)
+            } else {
+                None
+            }
+        })
+        .count()
+}
+
+// My original part 2, which only works on gardens like the real inputs - see the assumptions below.
+// [solve_part2] works on anything, but this one's still handy for checking it against.
+pub fn solve_part2_by_symmetry(grid: &Garden, target_distance: usize) -> usize {
+    // We're going to use a bunch of special properties of the grid:
+    // - our elf starts in the middle of the square grid.
+    // - there's a line of empty squares from the middle to the border (both horizontally and vertically).
+    // - There's also a line of empty squares around the border.
+    // (This is *not* true for the example though, which is kind of infuriating for checking!)
+    //
+    // So, let's take a square (i, j) in the grid. How many points in the big-grid have this reachable?
+    // Well, if it's reachable in the big-grid in exactly [target_distance] steps, both of these need
+    // to hold:
+    //
+    // (1) It's reachable in at most [target_distance] steps.
+    // (2) We're of the right parity of steps.
+    //
+    // For (1): Suppose we're trying to get to a big-square that's to the North-East of us - concretely,
+    // let's say it's (A, B) in the big grid, where we start at (0, 0), and A, B > 0. So we're
+    // going right and up. The best thing for us to do is to go along the middle route of empty squares
+    // until we get to the bottom or left boundary of that big-square, and then use whatever the
+    // shortest path is to get to that square.
+    //
+    // To get there, we must go (A-1) * width + (B-1) * height + (1/2 * width) + (1/2 * height) to get to the corner
+    // of that square, and then use shortest path to find if it's reachable. So we can use this to bound the
+    // sum of A and B, so find how many spots can be reached.
+    // The cases where we go to a square that's exactly to the North/East/West/South of us are similar. There
+    // are 8 cases below.
+
+    // First of all, let's assert at least one of those assumptions above - that the grid is square, and the elf
+    // starts in the middle.
+    assert!(grid.height == grid.width);
+    assert!(grid.height % 2 == 1);
+    assert!(grid.elf_position == Position(grid.height / 2, grid.height / 2));
+
+    // Do Dijkstra from each of the border squares, and also from the elf square.
+    let left_middle_square = Position(grid.height / 2, 0);
+    let right_middle_square = Position(grid.height / 2, grid.width - 1);
+    let top_middle_square = Position(0, grid.width / 2);
+    let bottom_middle_square = Position(grid.height - 1, grid.width / 2);
+    let top_left_corner = Position(0, 0);
+    let top_right_corner = Position(0, grid.width - 1);
+    let bottom_left_corner = Position(grid.height - 1, 0);
+    let bottom_right_corner = Position(grid.height - 1, grid.width - 1);
+
+    let distances_from_left_middle = dijkstra(grid, &left_middle_square);
+    let distances_from_right_middle = dijkstra(grid, &right_middle_square);
+    let distances_from_top_middle = dijkstra(grid, &top_middle_square);
+    let distances_from_bottom_middle = dijkstra(grid, &bottom_middle_square);
+    let distances_from_top_left = dijkstra(grid, &top_left_corner);
+    let distances_from_top_right = dijkstra(grid, &top_right_corner);
+    let distances_from_bottom_left = dijkstra(grid, &bottom_left_corner);
+    let distances_from_bottom_right = dijkstra(grid, &bottom_right_corner);
+    let distances_from_start = dijkstra(grid, &grid.elf_position);
+
+    let mut best_distances = HashMap::new();
+
+    for i in 0..grid.height {
+        for j in 0..grid.width {
+            let position = Position(i, j);
+
+            if !grid.adjacency_list.contains_key(&position) {
+                // This case isn't empty, so doesn't contribute anything.
+                continue;
+            }
+            if !distances_from_left_middle.contains_key(&position) {
+                // This is not connected to the boundary, so we're definitely not reaching it.
+                continue;
+            }
+
+            let mut total = 0;
+
+            // In how many big squares is this point reachable? We have 8 cases to consider:
+            // (A) Going in a straight line out from the origin - i.e. a big square to the left/top/right/bottom.
+            // (B) Not in a straight line.
+            // (C) Within this square.
+            //
+            // For (A), if we're reaching this square, we need to go:
+            // - To the border (costs grid.height / 2 + 1 squares)
+            // - And then from there to the square
+            //
+            // For (B) - for example if we're going to a square North-East of us - we need to go:
+            // (1) To the bottom-right corner of the square at (1, 1) (costs 2 * grid.height / 2 + 1)
+            // (2) Then A squares to the right/up (costs A * grid.height)
+            // (3) Then from the bottom-right corner to the target square.
+
+            let number_of_squares_case_a = |border_map: &HashMap<Position, usize>| {
+                let to_first_border = grid.height / 2 + 1;
+                let from_last_border = border_map.get(&position).unwrap();
+                let min_distance = from_last_border + to_first_border;
+
+                let number_of_full_squares_we_can_travel = {
+                    if target_distance < min_distance {
+                        0
+                    } else {
+                        // The [min_distance] already accounts for moving 1 square over.
+                        1 + (target_distance - min_distance) / grid.height
+                    }
+                };
+
+                // OK, so the number of possible squares is in [1..=number_of_full_squares_we_can_travel].
+                // But! We also need to check the parity. Going a square flips the parity of the square that
+                // we're on.
+                let parity_of_target_square_from_elf =
+                    distances_from_start.get(&position).unwrap() % 2;
+                let parity_of_target_distance = target_distance % 2;
+
+                if parity_of_target_square_from_elf == parity_of_target_distance {
+                    // Can only visit the even squares
+                    number_of_full_squares_we_can_travel / 2
+                } else {
+                    // Can only visit the odd squares
+                    number_of_full_squares_we_can_travel.div_ceil(2)
+                }
+            };
+
+            let number_of_squares_case_b = |corner_map: &HashMap<Position, usize>| {
+                // This needs 2* the above, because we need to go both one direction and the other.
+                let to_first_corner = 2 * (grid.height / 2 + 1);
+                let from_last_corner = corner_map.get(&position).unwrap();
+                let min_distance = from_last_corner + to_first_corner;
+
+                let number_of_full_squares_we_can_travel = {
+                    if target_distance < min_distance {
+                        0
+                    } else {
+                        // The [min_distance] already accounts for moving 2 square over.
+                        // (This is relying on the grid being square.)
+                        2 + (target_distance - min_distance) / grid.height
+                    }
+                };
+
+                // The number of squares we can get is the number of possibilities of (A, B) with:
+                // A+B matching the parity that we need
+                // A+B <= number_of_full_squares_we_can_travel
+                let parity_of_target_square_from_elf =
+                    distances_from_start.get(&position).unwrap() % 2;
+                let parity_of_target_distance = target_distance % 2;
+                let desired_big_square_parity = {
+                    if parity_of_target_distance == parity_of_target_square_from_elf {
+                        0
+                    } else {
+                        1
+                    }
+                };
+
+                // This is lazy of me - I should be able to find a closed form for this.
+                // But I'm tired, and this works well enough (my whole code runs in under 2s).
+                let mut total = 0;
+                for a in 1..number_of_full_squares_we_can_travel {
+                    let b = number_of_full_squares_we_can_travel - a;
+
+                    let desired_b_parity = {
+                        if a % 2 == 0 {
+                            desired_big_square_parity
+                        } else {
+                            1 - desired_big_square_parity
+                        }
+                    };
+
+                    if desired_b_parity == 0 {
+                        total += b / 2
+                    } else {
+                        total += b.div_ceil(2)
+                    }
+                }
+                total
+            };
+
+            total += number_of_squares_case_a(&distances_from_left_middle);
+            total += number_of_squares_case_a(&distances_from_top_middle);
+            total += number_of_squares_case_a(&distances_from_right_middle);
+            total += number_of_squares_case_a(&distances_from_bottom_middle);
+            total += number_of_squares_case_b(&distances_from_top_left);
+            total += number_of_squares_case_b(&distances_from_top_right);
+            total += number_of_squares_case_b(&distances_from_bottom_left);
+            total += number_of_squares_case_b(&distances_from_bottom_right);
+
+            // Don't forget the ones inside this square too!
+            total += {
+                let x = *distances_from_start.get(&position).unwrap();
+                if x <= target_distance && (x % 2) == (target_distance % 2) {
+                    1
+                } else {
+                    0
+                }
+            };
+            best_distances.insert(position, total);
+        }
+    }
+
+    best_distances.values().cloned().sum::<usize>()
+}
+
+// The plots of the garden repeated [tiles] times in each direction, and where the elf starts in the
+// middle copy. [tiles] has to be odd.
+fn tile(grid: &Garden, tiles: usize) -> (Grid<bool>, Position) {
+    let rows = (0..tiles * grid.height)
+        .map(|i| {
+            (0..tiles * grid.width)
+                .map(|j| grid.plots[Position(i % grid.height, j % grid.width)])
+                .collect()
+        })
+        .collect();
+    let Position(i, j) = grid.elf_position;
+    let middle = tiles / 2;
+    (
+        Grid::from_rows(rows),
+        Position(i + middle * grid.height, j + middle * grid.width),
+    )
+}
+
+// How many plots are exactly d steps away from [start] at best, for each d up to [max_steps]. A plain
+// breadth-first search, one layer at a time.
+fn count_by_distance(plots: &Grid<bool>, start: Position, max_steps: usize) -> Vec<usize> {
+    let mut seen = plots.map(|_| false);
+    seen[start] = true;
+    let mut layer = vec![start];
+    let mut counts = vec![];
+    while !layer.is_empty() && counts.len() <= max_steps {
+        counts.push(layer.len());
+        let mut next = vec![];
+        for cell in layer {
+            for neighbour in plots.neighbours(cell) {
+                if plots[neighbour] && !seen[neighbour] {
+                    seen[neighbour] = true;
+                    next.push(neighbour);
+                }
+            }
+        }
+        layer = next;
+    }
+    counts
+}
+
+// How many plots can be reached in exactly [steps] steps, given how many are at each distance - it's
+// the same parity argument as in [solve_part1].
+fn reachable(counts: &[usize], steps: usize) -> usize {
+    counts
+        .iter()
+        .take(steps + 1)
+        .skip(steps % 2)
+        .step_by(2)
+        .sum()
+}
+
+// How many periods [solve_part2] looks at before trying to extrapolate.
+const FIRST_PERIODS: usize = 6;
+
+pub fn solve_part2(grid: &Garden, target_distance: usize) -> Result<u64, &'static str> {
+    count_plots(grid, target_distance, false)
+}
+
+// Like [solve_part2], but it never just searches all the way to [target_distance] - it always
+// extrapolates from fewer steps, so that the extrapolation can be checked against a search (see
+// [verify]). It fails if the counts don't settle down soon enough before [target_distance].
+pub fn solve_part2_by_extrapolating(
+    grid: &Garden,
+    target_distance: usize,
+) -> Result<u64, &'static str> {
+    count_plots(grid, target_distance, true)
+}
+
+fn count_plots(
+    grid: &Garden,
+    target_distance: usize,
+    always_extrapolate: bool,
+) -> Result<u64, &'static str> {
+    // This works for any garden, not just ones like the real inputs.
+    //
+    // Tiles of the infinite garden are all the same, so if we go [period] steps further - a multiple
+    // of both the height and the width, and even so we're still on the same colour of the chessboard
+    // - the edge of the region we can reach looks the same as before, just one more tile out in
+    // every direction. Each tile inside it is full (or can't be reached at all), so the number of
+    // plots we can reach at [remainder], [remainder + period], [remainder + 2 * period], ... grows
+    // quadratically, once we're far enough out that the start has stopped mattering.
+    //
+    // So: count with a breadth-first search on a big enough patch of the tiled garden until those
+    // counts settle down - their third differences are zero - then extrapolate. If they don't
+    // settle down in the first few periods, try going further out.
+    let period = grid.period();
+    let remainder = target_distance % period;
+    let mut periods = FIRST_PERIODS;
+    while periods <= 48 {
+        let max_steps = target_distance.min(remainder + periods * period);
+        if max_steps == target_distance && always_extrapolate {
+            return Err("the counts don't settle down soon enough to extrapolate from");
+        }
+        // Enough tiles that we can't walk off the edge in [max_steps] steps.
+        let tiles = 2 * (max_steps / grid.height.min(grid.width) + 1) + 1;
+        let (plots, start) = tile(grid, tiles);
+        let counts = count_by_distance(&plots, start, max_steps);
+        if max_steps == target_distance {
+            return Ok(reachable(&counts, target_distance) as u64);
+        }
+
+        let samples: Vec<i128> = (0..=periods)
+            .map(|k| reachable(&counts, remainder + k * period) as i128)
+            .collect();
+        let differences = |xs: &[i128]| xs.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+        let settled = &samples[periods / 2 - 1..];
+        if differences(&differences(&differences(settled)))
+            .iter()
+            .all(|d| *d == 0)
+        {
+            // Carry on the quadratic from the last three samples.
+            let [a, b, c] = samples[periods - 2..] else {
+                unreachable!()
+            };
+            let (first, second) = (b - a, c - 2 * b + a);
+            let k = ((target_distance - remainder) / period - (periods - 2)) as i128;
+            let total = a + k * first + k * (k - 1) / 2 * second;
+            return u64::try_from(total).map_err(|_| "too many plots to count");
+        }
+        periods *= 2;
+    }
+    Err("the number of plots reached never settled down to growing quadratically")
+}
+
+// The first number of steps where a fast way of doing part 2 got a different count from just
+// searching the tiled garden.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub steps: usize,
+    pub expected: u64,
+    pub got: Result<u64, &'static str>,
+}
+
+// Checks [fast] - [solve_part2], say - against a search of the garden tiled [tiles] times in each
+// direction, for every number of steps in [steps]. They all need to fit inside the tiled garden, see
+// [Garden::steps_within].
+pub fn verify(
+    grid: &Garden,
+    tiles: usize,
+    steps: Range<usize>,
+    fast: impl Fn(&Garden, usize) -> Result<u64, &'static str>,
+) -> Result<(), Mismatch> {
+    assert!(
+        steps.end <= grid.steps_within(tiles) + 1,
+        "{tiles} tiles isn't enough to go {} steps",
+        steps.end - 1
+    );
+    // A breadth-first search rather than [dijkstra], which needs far too much memory for as many
+    // tiles as it takes to check the extrapolation on the real inputs.
+    let (plots, start) = tile(grid, tiles);
+    let counts = count_by_distance(&plots, start, steps.end.saturating_sub(1));
+
+    for steps in steps {
+        let expected = reachable(&counts, steps) as u64;
+        let got = fast(grid, steps);
+        if got != Ok(expected) {
+            return Err(Mismatch {
+                steps,
+                expected,
+                got,
+            });
+        }
+    }
+    Ok(())
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Garden;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        solve_part1(grid, 64).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        match solve_part2(grid, 26501365) {
+            Ok(plots) => plots.into(),
+            Err(e) => Answer::Failed(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+    #[test]
+    fn parses_example() {
+        let garden = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(garden.elf_position, Position(5, 5));
+        assert_eq!((garden.height, garden.width), (11, 11));
+        assert_eq!(garden.adjacency_list[&garden.elf_position].len(), 2);
+    }
+
+    #[test]
+    fn part1_example() {
+        // The example only goes 6 steps, rather than 64.
+        let garden = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(solve_part1(&garden, 6), 16);
+    }
+
+    // Steps the elf around the infinite garden one step at a time, keeping track of every plot it
+    // could be on, and gives how many there are after each step up to [steps]. Hopeless for the
+    // real number of steps, but it doesn't rely on anything clever.
+    pub fn brute_force(garden: &Garden, steps: usize) -> Vec<usize> {
+        let (height, width) = (garden.height as i64, garden.width as i64);
+        let is_plot = |(i, j): (i64, i64)| {
+            garden.plots[Position(i.rem_euclid(height) as usize, j.rem_euclid(width) as usize)]
+        };
+        let Position(i, j) = garden.elf_position;
+        let mut positions = HashSet::from([(i as i64, j as i64)]);
+        let mut counts = vec![1];
+        for _ in 0..steps {
+            positions = positions
+                .iter()
+                .flat_map(|(i, j)| [(i - 1, *j), (i + 1, *j), (*i, j - 1), (*i, j + 1)])
+                .filter(|p| is_plot(*p))
+                .collect();
+            counts.push(positions.len());
+        }
+        counts
+    }
+
+    #[test]
+    fn part2_example() {
+        let garden = Day21::parse(EXAMPLE).unwrap();
+        for (steps, plots) in [
+            (6, 16),
+            (10, 50),
+            (50, 1594),
+            (100, 6536),
+            (500, 167004),
+            (1000, 668697),
+            (5000, 16733044),
+        ] {
+            assert_eq!(solve_part2(&garden, steps), Ok(plots), "{steps} steps");
+        }
+    }
+
+    #[test]
+    fn part2_matches_brute_force() {
+        // Including a garden that isn't square, with the elf off in a corner. Both go far enough
+        // that [solve_part2] has to extrapolate.
+        let lopsided = "\
+S..#
+.#..
+..#.
+";
+        for (s, steps) in [(EXAMPLE, 160), (lopsided, 100)] {
+            let garden = Day21::parse(s).unwrap();
+            for (steps, plots) in brute_force(&garden, steps).into_iter().enumerate() {
+                assert_eq!(
+                    solve_part2(&garden, steps),
+                    Ok(plots as u64),
+                    "{steps} steps"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn verifies_against_the_tiled_garden() {
+        let garden = Day21::parse(EXAMPLE).unwrap();
+        let tiled = garden.tiled(3);
+        assert_eq!((tiled.height, tiled.width), (33, 33));
+        assert_eq!(tiled.elf_position, Position(16, 16));
+        assert_eq!(garden.steps_within(5), 27);
+
+        assert_eq!(verify(&garden, 5, 0..28, solve_part2), Ok(()));
+        // Something that's off by one after a while should get caught at the first step it's wrong.
+        let wrong = |garden: &Garden, steps| Ok(solve_part2(garden, steps)? + (steps >= 20) as u64);
+        assert_eq!(
+            verify(&garden, 5, 10..28, wrong),
+            Err(Mismatch {
+                steps: 20,
+                expected: brute_force(&garden, 20)[20] as u64,
+                got: Ok(brute_force(&garden, 20)[20] as u64 + 1),
+            })
+        );
+    }
+
+    #[test]
+    fn verifies_the_extrapolation() {
+        // [solve_part2] just searches for this few steps, so make sure it's the extrapolation
+        // that's being checked, for each remainder of the period.
+        let garden = Day21::parse(EXAMPLE).unwrap();
+        let (first, period) = (garden.first_extrapolated(), garden.period());
+        assert_eq!((first, period), (154, 22));
+        assert_eq!(
+            solve_part2_by_extrapolating(&garden, first - 1),
+            Err("the counts don't settle down soon enough to extrapolate from")
+        );
+        let tiles = garden.tiles_for(first + period);
+        assert_eq!(tiles, 33);
+        assert_eq!(
+            verify(
+                &garden,
+                tiles,
+                first..first + period,
+                solve_part2_by_extrapolating
+            ),
+            Ok(())
+        );
+        let steps = first + period;
+        assert_eq!(
+            solve_part2_by_extrapolating(&garden, steps),
+            Ok(brute_force(&garden, steps)[steps] as u64)
+        );
+    }
+
+    #[test]
+    fn part2_when_the_elf_is_stuck() {
+        let garden = Day21::parse("#.#\n#S#\n###\n").unwrap();
+        assert_eq!(solve_part2(&garden, 26501365), Ok(1));
+        assert_eq!(solve_part2(&garden, 26501364), Ok(1));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day21.txt and answers/day21.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day21>(21);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::Day22;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+impl Generate for Day22 {
+    const SIZE: &'static str = "bricks";
+    const DEFAULT_SIZE: usize = 1200;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        // Drop the bricks onto a 10 x 10 area one at a time, but leave a gap of up to 2 under each
+        // one so they still have some falling to do. Stacking them up like this means they can't
+        // overlap.
+        let mut heights = [[0; 10]; 10];
+        let mut bricks = vec![];
+        for _ in 0..size {
+            let length = rng.gen_range(0..=4);
+            let axis = rng.gen_range(0..3);
+            let [dx, dy, dz] = [0, 1, 2].map(|a| if a == axis { length } else { 0 });
+            let x = rng.gen_range(0..10 - dx);
+            let y = rng.gen_range(0..10 - dy);
+
+            let mut z = 0;
+            for x in x..=x + dx {
+                for y in y..=y + dy {
+                    z = z.max(heights[x][y]);
+                }
+            }
+            z += 1 + rng.gen_range(0..=2);
+            for x in x..=x + dx {
+                for y in y..=y + dy {
+                    heights[x][y] = z + dz;
+                }
+            }
+            bricks.push(format!(
+                "{},{},{}~{},{},{}",
+                x,
+                y,
+                z,
+                x + dx,
+                y + dy,
+                z + dz
+            ));
+        }
+
+        // They're in no particular order in the snapshot.
+        bricks.shuffle(rng);
+        bricks.join("\n") + "\n"
+    }
+}
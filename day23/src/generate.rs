@@ -0,0 +1,114 @@
+use crate::Day23;
+use aoc_common::generate::Generate;
+use aoc_common::grid::{Grid, Position};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// Where each row (or column) of junctions goes, with trails of 3 to 23 tiles between them.
+fn lines(rng: &mut StdRng, junctions: usize) -> Vec<usize> {
+    let mut lines = vec![rng.gen_range(2..=12)];
+    for _ in 1..junctions {
+        lines.push(lines.last().unwrap() + rng.gen_range(4..=24));
+    }
+    lines
+}
+
+impl Generate for Day23 {
+    const SIZE: &'static str = "junctions along each side";
+    const DEFAULT_SIZE: usize = 6;
+    const DENSITY: Option<&'static str> = Some("a trail between two junctions being blocked");
+    const DEFAULT_DENSITY: f64 = 0.0;
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String {
+        // The real maps are a square lattice of junctions, with a trail from the start to the
+        // top-left junction, and from the bottom-right junction to the end. The slopes on either
+        // end of every trail between junctions point right or down, so for part 1 it's always
+        // heading towards the end. This does the same, but with straight trails.
+        let size = size.max(1);
+        let rows = lines(rng, size);
+        let cols = lines(rng, size);
+        let height = rows.last().unwrap() + rng.gen_range(2..=12) + 1;
+        let width = cols.last().unwrap() + rng.gen_range(2..=12) + 1;
+        let mut grid = Grid::new(height, width, '#');
+
+        // Whatever gets blocked off, every junction needs a way in, from the left or from above.
+        // Otherwise it'd just be some trails that can't be reached, which the real maps don't have.
+        let mut kept = vec![];
+        for i in 0..size {
+            for j in 0..size {
+                let down = match (i, j) {
+                    (0, 0) => continue,
+                    (0, _) => false,
+                    (_, 0) => true,
+                    _ => rng.gen_bool(0.5),
+                };
+                kept.push(if down {
+                    ((i - 1, j), true)
+                } else {
+                    ((i, j - 1), false)
+                });
+            }
+        }
+
+        for i in 0..size {
+            for j in 0..size {
+                grid[Position(rows[i], cols[j])] = '.';
+                for down in [false, true] {
+                    let (from, to) = match down {
+                        true if i + 1 < size => (rows[i], rows[i + 1]),
+                        false if j + 1 < size => (cols[j], cols[j + 1]),
+                        _ => continue,
+                    };
+                    if !kept.contains(&((i, j), down)) && rng.gen_bool(density) {
+                        continue;
+                    }
+                    for x in from + 1..to {
+                        let (position, slope) = match down {
+                            true => (Position(x, cols[j]), 'v'),
+                            false => (Position(rows[i], x), '>'),
+                        };
+                        grid[position] = if x == from + 1 || x == to - 1 {
+                            slope
+                        } else {
+                            '.'
+                        };
+                    }
+                }
+            }
+        }
+
+        for row in 0..rows[0] {
+            grid[Position(row, cols[0])] = '.';
+        }
+        for row in rows[size - 1]..height {
+            grid[Position(row, cols[size - 1])] = '.';
+        }
+        grid.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solution::Solution;
+    use rand::SeedableRng;
+
+    #[test]
+    fn finds_the_longest_hikes_on_generated_maps() {
+        for seed in 0..10 {
+            let s = Day23::generate(&mut StdRng::seed_from_u64(seed), 4, 0.2);
+            let input = Day23::parse(&s).unwrap();
+            crate::tests::check_hike(&input, true, 4);
+        }
+    }
+
+    #[test]
+    fn more_than_64_junctions() {
+        // 83 junctions, counting the start and end, which is too many for a u64. Part 2 would take
+        // forever on a map this big, but with the slopes there's not much to search.
+        let s = Day23::generate(&mut StdRng::seed_from_u64(1), 9, 0.0);
+        let input = Day23::parse(&s).unwrap();
+        let hike = crate::tests::check_hike(&input, false, 2);
+        assert_eq!(Day23::part1(&input), hike.length.into());
+    }
+}
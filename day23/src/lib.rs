@@ -0,0 +1,591 @@
+use aoc_common::grid::{Dir, Grid, Position};
+use aoc_common::maze::{self, Cell, MazeGraph};
+use aoc_common::parse::ParseError;
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use tracing::debug;
+
+mod generate;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Space {
+    Empty,
+    Full,
+    SlopeUp,
+    SlopeDown,
+    SlopeRight,
+    SlopeLeft,
+}
+use Space::*;
+
+#[derive(Debug)]
+pub struct Trails {
+    grid: Grid<Space>,
+    start: Position,
+    end: Position,
+}
+
+fn parse(s: &str) -> Result<Trails, ParseError> {
+    let grid = Grid::parse(s, "'.', '#' or a slope", |c| match c {
+        '.' => Some(Empty),
+        '#' => Some(Full),
+        '^' => Some(SlopeUp),
+        '>' => Some(SlopeRight),
+        '<' => Some(SlopeLeft),
+        'v' => Some(SlopeDown),
+        _ => None,
+    })
+    .map_err(|e| e.locate(s))?;
+
+    let find_point_with_row = |row: usize| {
+        (0..grid.width())
+            .map(|j| Position(row, j))
+            .find(|p| grid[*p] == Empty)
+            .ok_or_else(|| {
+                let line = s.trim().lines().nth(row).unwrap_or_default();
+                ParseError::new(line, "a row with a gap in it").locate(s)
+            })
+    };
+    let start = find_point_with_row(0)?;
+    let end = find_point_with_row(grid.height().saturating_sub(1))?;
+
+    Ok(Trails { grid, start, end })
+}
+
+// The trails as a graph of junctions (plus the start and end), with the slopes only going downhill
+// unless we're ignoring the ice.
+fn to_graph(trails: &Trails, ignore_ice: bool) -> MazeGraph {
+    let keep = [trails.start, trails.end];
+    maze::compress(&trails.grid, &keep, |space| match (space, ignore_ice) {
+        (Full, _) => Cell::Wall,
+        (Empty, _) | (_, true) => Cell::Open,
+        (SlopeUp, false) => Cell::OneWay(Dir::Up),
+        (SlopeDown, false) => Cell::OneWay(Dir::Down),
+        (SlopeLeft, false) => Cell::OneWay(Dir::Left),
+        (SlopeRight, false) => Cell::OneWay(Dir::Right),
+    })
+}
+
+// The compressed graph with its vertices numbered, so a set of them fits in the bits of a
+// [JunctionSet].
+struct Junctions {
+    positions: Vec<Position>,
+    // (to, weight) for each edge out of each junction.
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    end: usize,
+}
+
+impl Junctions {
+    // Numbered the same way as the graph's vertices.
+    fn new(graph: &MazeGraph, start: Position, end: Position) -> Self {
+        let positions = graph.vertices.clone();
+        // Where there are two trails between the same junctions, only the longer one matters.
+        let edges = graph
+            .edges
+            .iter()
+            .map(|edges| {
+                let mut longest: HashMap<usize, usize> = HashMap::new();
+                for edge in edges {
+                    let length = longest.entry(edge.to).or_insert(0);
+                    *length = edge.length.max(*length);
+                }
+                let mut edges: Vec<(usize, usize)> = longest.into_iter().collect();
+                edges.sort();
+                edges
+            })
+            .collect();
+        Junctions {
+            edges,
+            start: graph.vertex(start).unwrap(),
+            end: graph.vertex(end).unwrap(),
+            positions,
+        }
+    }
+}
+
+// The longest hike, and the junctions it goes through on the way, including the start and end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hike {
+    pub length: usize,
+    pub junctions: Vec<Position>,
+    // Every step of the way, from the start to the end.
+    pub cells: Vec<Position>,
+}
+
+// A set of junctions, by number. The real inputs have fewer than 64, so a u64 does and keeps the
+// search quick, but bigger maps need something wider.
+trait JunctionSet: Clone + Send + Sync {
+    fn empty(n: usize) -> Self;
+    fn contains(&self, i: usize) -> bool;
+    fn insert(&mut self, i: usize);
+    fn remove(&mut self, i: usize);
+    // Everything in this set or in [other].
+    fn union(&self, other: &Self) -> Self;
+    // Everything in this set but not in [other].
+    fn without(&self, other: &Self) -> Self;
+    fn is_empty(&self) -> bool;
+    fn for_each(&self, f: impl FnMut(usize));
+
+    fn with(&self, i: usize) -> Self {
+        let mut set = self.clone();
+        set.insert(i);
+        set
+    }
+}
+
+impl JunctionSet for u64 {
+    fn empty(_n: usize) -> Self {
+        0
+    }
+
+    fn contains(&self, i: usize) -> bool {
+        self & (1 << i) != 0
+    }
+
+    fn insert(&mut self, i: usize) {
+        *self |= 1 << i;
+    }
+
+    fn remove(&mut self, i: usize) {
+        *self &= !(1 << i);
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    fn without(&self, other: &Self) -> Self {
+        self & !other
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == 0
+    }
+
+    fn for_each(&self, mut f: impl FnMut(usize)) {
+        let mut bits = *self;
+        while bits != 0 {
+            f(bits.trailing_zeros() as usize);
+            bits &= bits - 1;
+        }
+    }
+}
+
+// Any number of junctions, 64 to a word.
+impl JunctionSet for Vec<u64> {
+    fn empty(n: usize) -> Self {
+        vec![0; n.div_ceil(64)]
+    }
+
+    fn contains(&self, i: usize) -> bool {
+        self[i / 64].contains(i % 64)
+    }
+
+    fn insert(&mut self, i: usize) {
+        self[i / 64].insert(i % 64);
+    }
+
+    fn remove(&mut self, i: usize) {
+        self[i / 64].remove(i % 64);
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        self.iter().zip(other).map(|(a, b)| a | b).collect()
+    }
+
+    fn without(&self, other: &Self) -> Self {
+        self.iter().zip(other).map(|(a, b)| a & !b).collect()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.iter().all(|word| *word == 0)
+    }
+
+    fn for_each(&self, mut f: impl FnMut(usize)) {
+        for (w, word) in self.iter().enumerate() {
+            word.for_each(|i| f(w * 64 + i));
+        }
+    }
+}
+
+// The length of the best hike a thread has found, and the junctions along it, by number.
+type Best = Option<(usize, Vec<usize>)>;
+
+// A depth-first search through every hike, which gives up on any hike that can't beat the best one
+// so far (found by any thread).
+struct Search<'a, S> {
+    junctions: &'a Junctions,
+    // The edges out of each junction, as a set.
+    out: Vec<S>,
+    // The longest edge into each junction. Every junction the hike still visits gets there along
+    // one edge, so adding these up for the junctions it can still get to is an upper bound on how
+    // much longer it can get.
+    longest_in: Vec<usize>,
+    // In the real inputs, only one junction leads to the end. Once the hike gets there, it has to
+    // go to the end, since going anywhere else would cut it off.
+    last_before_end: Option<usize>,
+    best: &'a AtomicUsize,
+}
+
+// A partly explored hike. [visited] has the junctions in [path] set.
+#[derive(Debug, Clone)]
+struct PartialHike<S> {
+    path: Vec<usize>,
+    visited: S,
+    length: usize,
+}
+
+impl<S: JunctionSet> Search<'_, S> {
+    fn new<'a>(junctions: &'a Junctions, best: &'a AtomicUsize) -> Search<'a, S> {
+        let n = junctions.positions.len();
+        let mut out = vec![S::empty(n); n];
+        let mut longest_in = vec![0; n];
+        let mut into_end = vec![];
+        for (from, edges) in junctions.edges.iter().enumerate() {
+            for (to, weight) in edges {
+                out[from].insert(*to);
+                longest_in[*to] = longest_in[*to].max(*weight);
+                if *to == junctions.end {
+                    into_end.push(from);
+                }
+            }
+        }
+        let last_before_end = match into_end[..] {
+            [from] => Some(from),
+            _ => None,
+        };
+        Search {
+            junctions,
+            out,
+            longest_in,
+            last_before_end,
+            best,
+        }
+    }
+
+    // The junctions that can be reached from [from] without going through [visited].
+    fn reachable(&self, from: usize, visited: &S) -> S {
+        let n = self.out.len();
+        let mut reached = S::empty(n);
+        let mut frontier = S::empty(n).with(from);
+        while !frontier.is_empty() {
+            reached = reached.union(&frontier);
+            let mut next = S::empty(n);
+            frontier.for_each(|i| next = next.union(&self.out[i]));
+            frontier = next.without(visited).without(&reached);
+        }
+        reached
+    }
+
+    // The ways the hike can carry on from where it is.
+    fn next<'b>(&'b self, hike: &'b PartialHike<S>) -> impl Iterator<Item = (usize, usize)> + 'b {
+        let at = *hike.path.last().unwrap();
+        let forced = self.last_before_end == Some(at);
+        self.junctions.edges[at]
+            .iter()
+            .copied()
+            .filter(move |(to, _weight)| {
+                !hike.visited.contains(*to) && (!forced || *to == self.junctions.end)
+            })
+    }
+
+    fn search(&self, hike: &mut PartialHike<S>, best: &mut Best) {
+        let at = *hike.path.last().unwrap();
+        if at == self.junctions.end {
+            if best
+                .as_ref()
+                .is_none_or(|(length, _path)| hike.length > *length)
+            {
+                self.best.fetch_max(hike.length, Ordering::Relaxed);
+                *best = Some((hike.length, hike.path.clone()));
+            }
+            return;
+        }
+
+        let mut reachable = self.reachable(at, &hike.visited);
+        if !reachable.contains(self.junctions.end) {
+            return;
+        }
+        reachable.remove(at);
+        let mut bound = 0;
+        reachable.for_each(|i| bound += self.longest_in[i]);
+        // Another hike (maybe found by another thread, which has kept it) already gets at least
+        // this far.
+        if hike.length + bound <= self.best.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let next: Vec<(usize, usize)> = self.next(hike).collect();
+        for (to, weight) in next {
+            hike.path.push(to);
+            hike.visited.insert(to);
+            hike.length += weight;
+            self.search(hike, best);
+            hike.length -= weight;
+            hike.visited.remove(to);
+            hike.path.pop();
+        }
+    }
+}
+
+// The longest hike from the start to the end which doesn't visit any junction twice, split between
+// [threads] threads. Finding the longest path in a graph is NP-hard, so this is a (well pruned)
+// brute force search.
+fn solve<S: JunctionSet>(junctions: &Junctions, threads: usize) -> Best {
+    let best_length = AtomicUsize::new(0);
+    let search = Search::<S>::new(junctions, &best_length);
+    let start = PartialHike {
+        path: vec![junctions.start],
+        visited: S::empty(junctions.positions.len()).with(junctions.start),
+        length: 0,
+    };
+
+    if threads <= 1 {
+        let mut best = None;
+        search.search(&mut start.clone(), &mut best);
+        return best;
+    }
+
+    // Split the search up by exploring the first few steps of every hike breadth first, until there
+    // are plenty of them to share out between the threads.
+    let mut tasks = vec![start];
+    while tasks.len() < threads * 16 {
+        let at_end = |hike: &PartialHike<S>| *hike.path.last().unwrap() == junctions.end;
+        if tasks.iter().all(at_end) {
+            break;
+        }
+        tasks = tasks
+            .into_iter()
+            .flat_map(|hike| {
+                if at_end(&hike) {
+                    return vec![hike];
+                }
+                search
+                    .next(&hike)
+                    .map(|(to, weight)| PartialHike {
+                        path: [&hike.path[..], &[to]].concat(),
+                        visited: hike.visited.with(to),
+                        length: hike.length + weight,
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+    debug!("searching from {} partial hikes", tasks.len());
+
+    let tasks = Mutex::new(tasks);
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut best = None;
+                    while let Some(mut hike) = tasks.lock().unwrap().pop() {
+                        search.search(&mut hike, &mut best);
+                    }
+                    best
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().unwrap())
+            .max_by_key(|(length, _path)| *length)
+    })
+}
+
+pub fn longest_hike(trails: &Trails, ignore_ice: bool, threads: usize) -> Option<Hike> {
+    let graph = to_graph(trails, ignore_ice);
+    debug!("{} junctions", graph.vertices.len());
+    let junctions = Junctions::new(&graph, trails.start, trails.end);
+
+    let (length, path) = match junctions.positions.len() {
+        0..=64 => solve::<u64>(&junctions, threads),
+        _ => solve::<Vec<u64>>(&junctions, threads),
+    }?;
+    let cells = graph.cells(&path).unwrap();
+    assert_eq!(cells.len(), length + 1);
+    Some(Hike {
+        length,
+        junctions: path.iter().map(|i| graph.vertices[*i]).collect(),
+        cells,
+    })
+}
+
+fn answer(hike: Option<Hike>) -> Answer {
+    match hike {
+        Some(hike) => hike.length.into(),
+        None => Answer::Failed("there's no way to the end".to_string()),
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Trails;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(trails: &Self::Input) -> Answer {
+        // With the slopes, the junctions in the real input only lead down and right, so there's
+        // not much searching to do.
+        answer(longest_hike(trails, false, 1))
+    }
+
+    fn part2(trails: &Self::Input) -> Answer {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        answer(longest_hike(trails, true, threads))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    const EXAMPLE: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    #[test]
+    fn parses_example() {
+        let trails = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(trails.start, Position(0, 1));
+        assert_eq!(trails.end, Position(22, 21));
+        assert_eq!(trails.grid[Position(3, 10)], SlopeRight);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(Day23::part1(&input), Answer::Integer(94));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(Day23::part2(&input), Answer::Integer(154));
+    }
+
+    // Checks the hike against the map, and against trying every hike without any pruning.
+    pub fn check_hike(trails: &Trails, ignore_ice: bool, threads: usize) -> Hike {
+        let hike = longest_hike(trails, ignore_ice, threads).unwrap();
+
+        // Each step is to the next cell along, in the direction of the slope if it's on one.
+        let cells = &hike.cells;
+        assert_eq!(
+            (cells[0], cells[cells.len() - 1]),
+            (trails.start, trails.end)
+        );
+        assert_eq!(cells.len(), hike.length + 1);
+        for w in cells.windows(2) {
+            assert!(trails.grid.neighbours(w[0]).any(|p| p == w[1]));
+            assert!(neighbours(trails, w[0], ignore_ice).contains(&w[1]));
+        }
+        let distinct: HashSet<&Position> = cells.iter().collect();
+        assert_eq!(distinct.len(), cells.len());
+        assert!(hike.junctions.iter().all(|p| cells.contains(p)));
+
+        fn every_hike(
+            graph: &MazeGraph,
+            at: usize,
+            end: usize,
+            visited: &mut HashSet<usize>,
+        ) -> Option<usize> {
+            if at == end {
+                return Some(0);
+            }
+            let mut best = None;
+            for edge in &graph.edges[at] {
+                if visited.insert(edge.to) {
+                    let rest = every_hike(graph, edge.to, end, visited);
+                    best = best.max(rest.map(|rest| rest + edge.length));
+                    visited.remove(&edge.to);
+                }
+            }
+            best
+        }
+        let graph = to_graph(trails, ignore_ice);
+        let start = graph.vertex(trails.start).unwrap();
+        let end = graph.vertex(trails.end).unwrap();
+        let mut visited = HashSet::from([start]);
+        assert_eq!(
+            every_hike(&graph, start, end, &mut visited),
+            Some(hike.length)
+        );
+        hike
+    }
+
+    // Where you can step to from [position], one cell at a time.
+    fn neighbours(trails: &Trails, position: Position, ignore_ice: bool) -> Vec<Position> {
+        let grid = &trails.grid;
+        let slope = |dir: Dir| grid.step(position, dir).into_iter().collect();
+
+        match (grid[position], ignore_ice) {
+            (SlopeUp, false) => slope(Dir::Up),
+            (SlopeDown, false) => slope(Dir::Down),
+            (SlopeLeft, false) => slope(Dir::Left),
+            (SlopeRight, false) => slope(Dir::Right),
+            _ => grid
+                .neighbours(position)
+                .filter(|p| grid[*p] != Full)
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn finds_the_hikes() {
+        let trails = Day23::parse(EXAMPLE).unwrap();
+        for threads in [1, 4] {
+            assert_eq!(check_hike(&trails, false, threads).length, 94);
+            let hike = check_hike(&trails, true, threads);
+            assert_eq!(hike.length, 154);
+            assert_eq!(hike.junctions.len(), 9);
+        }
+    }
+
+    #[test]
+    fn no_way_to_the_end() {
+        // The slope at the bottom points back up.
+        let trails = Day23::parse("#.#\n#.#\n#^#\n#.#\n").unwrap();
+        assert_eq!(longest_hike(&trails, false, 1), None);
+        assert_eq!(
+            Day23::part1(&trails),
+            Answer::Failed("there's no way to the end".to_string())
+        );
+        assert_eq!(Day23::part2(&trails), Answer::Integer(3));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day23.txt and answers/day23.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day23>(23);
+    }
+}
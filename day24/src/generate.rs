@@ -0,0 +1,67 @@
+use crate::Day24;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::HashSet;
+
+impl Generate for Day24 {
+    const SIZE: &'static str = "hailstones";
+    const DEFAULT_SIZE: usize = 300;
+    // Part 2 needs 3 hailstones to pin the rock down.
+    const MIN_SIZE: usize = 3;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        hailstones(rng, size).0
+    }
+}
+
+// The hailstones, and the answer to part 2.
+fn hailstones(rng: &mut StdRng, size: usize) -> (String, i64) {
+    // Throw a rock first, then work backwards to hailstones that it hits, each at a different
+    // time. The hailstones never move at the same speed as the rock in any direction, so every
+    // one of them is needed to pin down where the rock is.
+    let rock_position: [i64; 3] =
+        [(); 3].map(|_| rng.gen_range(100_000_000_000_000..400_000_000_000_000));
+    let rock_velocity: [i64; 3] = [(); 3].map(|_| rng.gen_range(-300..=300));
+
+    let mut times = HashSet::new();
+    let mut result = String::new();
+    while times.len() < size {
+        let time: i64 = rng.gen_range(1..=500_000_000_000);
+        let velocity = rock_velocity.map(|v| loop {
+            let velocity = rng.gen_range(-300..=300);
+            if velocity != v {
+                break velocity;
+            }
+        });
+        let position: Vec<i64> = (0..3)
+            .map(|i| rock_position[i] + (rock_velocity[i] - velocity[i]) * time)
+            .collect();
+        // The real hailstones all start at positive positions.
+        if position.iter().any(|p| *p <= 0) || !times.insert(time) {
+            continue;
+        }
+        result += &format!(
+            "{}, {}, {} @ {}, {}, {}\n",
+            position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+        );
+    }
+    (result, rock_position.iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solution::{Answer, Solution};
+    use rand::SeedableRng;
+
+    #[test]
+    fn finds_the_planted_rock() {
+        for seed in 0..10 {
+            let (s, rock) = hailstones(&mut StdRng::seed_from_u64(seed), 50);
+            let input = Day24::parse(&s).unwrap();
+            assert_eq!(input.len(), 50);
+            assert_eq!(Day24::part2(&input), Answer::from(rock));
+        }
+    }
+}
@@ -0,0 +1,649 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, Zero};
+use tracing::{debug, trace};
+
+mod generate;
+
+#[derive(Debug)]
+pub struct Hailstone {
+    pub position: (i64, i64, i64),
+    pub velocity: (i64, i64, i64),
+}
+
+// A point (or a direction) in 2D or 3D.
+pub type Point = Vec<BigRational>;
+
+// A stretch of a line, from [start] to start + length * direction, or carrying on forever if
+// there's no [length].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stretch {
+    pub start: Point,
+    pub direction: Point,
+    pub length: Option<BigRational>,
+}
+
+// How the future paths of two hailstones cross, if they do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Crossing {
+    // The paths are parallel, and never meet. A hailstone that isn't moving (maybe just in x and
+    // y) counts as parallel to everything.
+    Parallel,
+    // Only in 3D: the paths aren't parallel, but still never meet.
+    Skew,
+    // The paths meet, but at least one of the hailstones had already been there.
+    InPast,
+    // Both hailstones will pass through this point.
+    At(Point),
+    // The paths run along the same line, and both hailstones will pass through all of this
+    // stretch of it. Not necessarily at the same time, though.
+    Overlapping(Stretch),
+}
+use Crossing::*;
+
+fn rational(x: i64) -> BigRational {
+    BigRational::from_integer(x.into())
+}
+
+// The hailstone's position and velocity, in the first few dimensions. These are kept as integers
+// for as long as possible - doing everything in rationals makes part 1 about ten times slower.
+fn ray(hailstone: &Hailstone, dimensions: usize) -> (Vec<BigInt>, Vec<BigInt>) {
+    let (x, y, z) = hailstone.position;
+    let (a, b, c) = hailstone.velocity;
+    let position = [x, y, z].map(BigInt::from)[..dimensions].to_vec();
+    let velocity = [a, b, c].map(BigInt::from)[..dimensions].to_vec();
+    (position, velocity)
+}
+
+// The (i, j) coordinates of the cross product, for every pair of coordinates. In 2D that's just the
+// one, and in 3D it's the whole cross product (up to sign). Two vectors are parallel if they're all
+// zero.
+fn minors(a: &[BigInt], b: &[BigInt]) -> Vec<(usize, usize, BigInt)> {
+    let mut result = vec![];
+    for i in 0..a.len() {
+        for j in i + 1..a.len() {
+            result.push((i, j, &a[i] * &b[j] - &a[j] * &b[i]));
+        }
+    }
+    result
+}
+
+fn is_parallel(a: &[BigInt], b: &[BigInt]) -> bool {
+    minors(a, b).iter().all(|(_i, _j, m)| m.is_zero())
+}
+
+// Where the paths p1 + s * v1 and p2 + t * v2 cross, for s, t >= 0.
+fn crossing(p1: &[BigInt], v1: &[BigInt], p2: &[BigInt], v2: &[BigInt]) -> Crossing {
+    let d: Vec<BigInt> = p2.iter().zip(p1).map(|(a, b)| a - b).collect();
+
+    // The usual case: solve s * v1 - t * v2 = p2 - p1 using two of the coordinates where that can
+    // be done, and then check the others (if there are any) agree. s and t are (s_m / m) and
+    // (t_m / m), so everything's multiplied through by m to stay in integers.
+    if let Some((i, j, m)) = minors(v1, v2).into_iter().find(|(_i, _j, m)| !m.is_zero()) {
+        let s_m = &d[i] * &v2[j] - &d[j] * &v2[i];
+        let t_m = &d[i] * &v1[j] - &d[j] * &v1[i];
+        let point_m: Vec<BigInt> = p1.iter().zip(v1).map(|(p, v)| p * &m + &s_m * v).collect();
+        let meets = p2
+            .iter()
+            .zip(v2)
+            .zip(&point_m)
+            .all(|((p, v), x)| p * &m + &t_m * v == *x);
+        let in_past = |x_m: &BigInt| x_m.is_positive() != m.is_positive() && !x_m.is_zero();
+        return if !meets {
+            Skew
+        } else if in_past(&s_m) || in_past(&t_m) {
+            InPast
+        } else {
+            At(point_m
+                .into_iter()
+                .map(|x| BigRational::new(x, m.clone()))
+                .collect())
+        };
+    }
+
+    // Otherwise the paths are parallel. They're the same line if the gap between the starting
+    // points is along it too. Use whichever hailstone is moving to find which way the line goes.
+    let Some(u) = [v1, v2]
+        .into_iter()
+        .find(|v| v.iter().any(|x| !x.is_zero()))
+    else {
+        // Neither hailstone is moving.
+        return if d.iter().all(|x| x.is_zero()) {
+            Overlapping(Stretch {
+                start: p1.iter().cloned().map(BigRational::from_integer).collect(),
+                direction: vec![BigRational::zero(); p1.len()],
+                length: None,
+            })
+        } else {
+            Parallel
+        };
+    };
+    if !is_parallel(&d, u) {
+        return Parallel;
+    }
+
+    // Which points on the line each hailstone will pass through, as a range of one coordinate
+    // where the line isn't flat. None at either end means it carries on forever that way.
+    let k = u.iter().position(|x| !x.is_zero()).unwrap();
+    type Range = (Option<BigInt>, Option<BigInt>);
+    let future = |p: &[BigInt], v: &[BigInt]| -> Range {
+        let start = Some(p[k].clone());
+        if v[k].is_positive() {
+            (start, None)
+        } else if v[k].is_negative() {
+            (None, start)
+        } else {
+            (start.clone(), start)
+        }
+    };
+    let (lo1, hi1) = future(p1, v1);
+    let (lo2, hi2) = future(p2, v2);
+    let lo = lo1.into_iter().chain(lo2).max();
+    let hi = hi1.into_iter().chain(hi2).min();
+    if let (Some(lo), Some(hi)) = (&lo, &hi) {
+        if lo > hi {
+            // Each hailstone has already been where the other one's going.
+            return InPast;
+        }
+    }
+
+    // Back from a coordinate to a point on the line, and a direction which moves one unit
+    // along that coordinate.
+    let at = |x: &BigInt| -> Point {
+        p1.iter()
+            .zip(u)
+            .map(|(p, v)| BigRational::new(p * &u[k] + (x - &p1[k]) * v, u[k].clone()))
+            .collect()
+    };
+    let direction: Point = u
+        .iter()
+        .map(|x| BigRational::new(x.clone(), u[k].clone()))
+        .collect();
+    Overlapping(match (lo, hi) {
+        (Some(lo), hi) => Stretch {
+            start: at(&lo),
+            direction,
+            length: hi.map(|hi| BigRational::from_integer(hi - lo)),
+        },
+        (None, Some(hi)) => Stretch {
+            start: at(&hi),
+            direction: direction.into_iter().map(|x| -x).collect(),
+            length: None,
+        },
+        (None, None) => unreachable!("Both hailstones can't be going both ways"),
+    })
+}
+
+// Where the hailstones' paths cross, looking at just x and y.
+pub fn crossing_ignoring_z(h1: &Hailstone, h2: &Hailstone) -> Crossing {
+    // We want to solve p1 + t1*v1 == p2 + t2*v2.
+    // (Or, to be more precise, know if there is a solution with t1, t2 >= 0.)
+    // This used to use slopes and intercepts in floats, which lost precision with numbers this big,
+    // and couldn't cope with the paths being the same line. So now it's exact.
+    let (p1, v1) = ray(h1, 2);
+    let (p2, v2) = ray(h2, 2);
+    crossing(&p1, &v1, &p2, &v2)
+}
+
+// Where the hailstones' paths cross in 3D. Almost every pair of paths in the real input is skew.
+pub fn crossing_in_3d(h1: &Hailstone, h2: &Hailstone) -> Crossing {
+    let (p1, v1) = ray(h1, 3);
+    let (p2, v2) = ray(h2, 3);
+    crossing(&p1, &v1, &p2, &v2)
+}
+
+fn parse_tuple(s: &str) -> Result<(i64, i64, i64), ParseError> {
+    let [x, y, z]: [i64; 3] = s
+        .split(",")
+        .map(|s| parse::number(s.trim()))
+        .collect::<Result<Vec<i64>, _>>()?
+        .try_into()
+        .map_err(|_| ParseError::new(s, "3 numbers, x, y, z"))?;
+    Ok((x, y, z))
+}
+
+fn parse_hailstone(line: &str) -> Result<Hailstone, ParseError> {
+    let (position, velocity) = parse::split_once(line, " @ ")?;
+
+    Ok(Hailstone {
+        position: parse_tuple(position)?,
+        velocity: parse_tuple(velocity)?,
+    })
+}
+
+fn parse(s: &str) -> Result<Vec<Hailstone>, ParseError> {
+    s.trim()
+        .lines()
+        .map(parse_hailstone)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(s))
+}
+
+// Where to look for crossings, inclusive at both ends.
+#[derive(Debug, Clone)]
+pub struct TestArea {
+    pub x: (i64, i64),
+    pub y: (i64, i64),
+}
+
+impl TestArea {
+    pub fn square(min: i64, max: i64) -> Self {
+        TestArea {
+            x: (min, max),
+            y: (min, max),
+        }
+    }
+
+    fn ranges(&self) -> [(BigRational, BigRational); 2] {
+        [self.x, self.y].map(|(min, max)| (rational(min), rational(max)))
+    }
+
+    pub fn contains(&self, point: &[BigRational]) -> bool {
+        self.ranges()
+            .iter()
+            .zip(point)
+            .all(|((min, max), x)| min <= x && x <= max)
+    }
+
+    // Whether any of the stretch is inside the test area. Each coordinate being in range gives a
+    // range of how far along the stretch it can be, so see if they all overlap.
+    pub fn meets(&self, stretch: &Stretch) -> bool {
+        let mut lo = BigRational::zero();
+        let mut hi = stretch.length.clone();
+        for (((min, max), start), direction) in self
+            .ranges()
+            .into_iter()
+            .zip(&stretch.start)
+            .zip(&stretch.direction)
+        {
+            if direction.is_zero() {
+                if start < &min || start > &max {
+                    return false;
+                }
+                continue;
+            }
+            let a = (&min - start) / direction;
+            let b = (&max - start) / direction;
+            let (a, b) = if a <= b { (a, b) } else { (b, a) };
+            lo = lo.max(a);
+            hi = Some(match hi {
+                Some(hi) => hi.min(b),
+                None => b,
+            });
+        }
+        hi.is_none_or(|hi| lo <= hi)
+    }
+}
+
+// The number of future crossings of the hailstones' paths inside the test area, ignoring z.
+pub fn count_intersections_in_test_area(hailstones: &[Hailstone], area: &TestArea) -> usize {
+    let mut result1 = 0;
+
+    for i in 0..hailstones.len() {
+        for j in i + 1..hailstones.len() {
+            let h1 = &hailstones[i];
+            let h2 = &hailstones[j];
+            let crossing = crossing_ignoring_z(h1, h2);
+
+            match crossing {
+                Parallel => trace!(?h1.velocity, ?h2.velocity, "parallel hailstones"),
+                Skew => unreachable!("Paths in 2D can't be skew"),
+                InPast => (),
+                At(point) => {
+                    if area.contains(&point) {
+                        result1 += 1;
+                    }
+                }
+                Overlapping(stretch) => {
+                    trace!(?h1, ?h2, "hailstones on the same path");
+                    if area.meets(&stretch) {
+                        result1 += 1;
+                    }
+                }
+            }
+        }
+    }
+    result1
+}
+
+// A rock that hits every hailstone, thrown from [position] at time 0.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rock {
+    pub position: [BigInt; 3],
+    pub velocity: [BigInt; 3],
+}
+
+type Vector = [BigRational; 3];
+
+fn vector((x, y, z): (i64, i64, i64)) -> Vector {
+    [x, y, z].map(|a| BigRational::from_integer(a.into()))
+}
+
+fn minus(a: &Vector, b: &Vector) -> Vector {
+    [0, 1, 2].map(|i| &a[i] - &b[i])
+}
+
+fn cross(a: &Vector, b: &Vector) -> Vector {
+    [
+        &a[1] * &b[2] - &a[2] * &b[1],
+        &a[2] * &b[0] - &a[0] * &b[2],
+        &a[0] * &b[1] - &a[1] * &b[0],
+    ]
+}
+
+// The three linear equations in the rock's position and velocity (px, py, pz, vx, vy, vz) which
+// come from it hitting two of the hailstones, as rows of coefficients followed by the constant.
+//
+// If the rock is at P with velocity V, it hits a hailstone at p with velocity v when P - p and
+// V - v are parallel, i.e. (P - p) x (V - v) = 0. That's not linear, since there's a P x V term,
+// but it's the same P x V for every hailstone - so subtracting the equations for two hailstones
+// leaves P x (v1 - v2) + (p1 - p2) x V = p1 x v1 - p2 x v2.
+fn equations(h1: &Hailstone, h2: &Hailstone) -> [Vec<BigRational>; 3] {
+    let (p1, v1) = (vector(h1.position), vector(h1.velocity));
+    let (p2, v2) = (vector(h2.position), vector(h2.velocity));
+    let a = minus(&v1, &v2);
+    let b = minus(&p1, &p2);
+    let c = minus(&cross(&p1, &v1), &cross(&p2, &v2));
+    let zero = BigRational::zero;
+
+    let [ax, ay, az] = a;
+    let [bx, by, bz] = b;
+    let [cx, cy, cz] = c;
+    [
+        vec![zero(), az.clone(), -&ay, zero(), -&bz, by.clone(), cx],
+        vec![-&az, zero(), ax.clone(), bz, zero(), -&bx, cy],
+        vec![ay, -ax, zero(), -by, bx, zero(), cz],
+    ]
+}
+
+// When the rock hits the hailstone, if it ever does.
+fn time_of_hit(position: &Vector, velocity: &Vector, hailstone: &Hailstone) -> Option<BigRational> {
+    // P + tV = p + tv, so P - p = t(v - V).
+    let offset = minus(position, &vector(hailstone.position));
+    let closing = minus(&vector(hailstone.velocity), velocity);
+    let time = match (0..3).find(|i| !closing[*i].is_zero()) {
+        Some(i) => &offset[i] / &closing[i],
+        // Moving together, so they'd better be in the same place to start with.
+        None => BigRational::zero(),
+    };
+    let hits = (0..3).all(|i| offset[i] == &time * &closing[i]);
+    (hits && !time.is_negative()).then_some(time)
+}
+
+// Finds the rock with exact arithmetic - the numbers in the real input are about 10^14, and their
+// products are far too big for the 53 bits of precision in an f64.
+pub fn throw_rock(hailstones: &[Hailstone]) -> Result<Rock, &'static str> {
+    // Gauss-Jordan elimination, a pair of hailstones at a time, until there's a pivot for each of
+    // the 6 unknowns. Usually the first three hailstones are enough. [basis] is kept fully reduced,
+    // so each row has a 1 in its pivot column and every other row has a 0 there.
+    let mut basis: Vec<(usize, Vec<BigRational>)> = vec![];
+    let pairs = (0..hailstones.len()).flat_map(|i| (i + 1..hailstones.len()).map(move |j| (i, j)));
+    'pairs: for (i, j) in pairs {
+        for mut row in equations(&hailstones[i], &hailstones[j]) {
+            for (pivot, basis_row) in &basis {
+                let factor = row[*pivot].clone();
+                if !factor.is_zero() {
+                    for (x, y) in row.iter_mut().zip(basis_row) {
+                        *x -= &factor * y;
+                    }
+                }
+            }
+            let Some(pivot) = (0..6).find(|k| !row[*k].is_zero()) else {
+                if !row[6].is_zero() {
+                    return Err("no rock hits every hailstone");
+                }
+                continue;
+            };
+            let scale = row[pivot].clone();
+            row.iter_mut().for_each(|x| *x /= &scale);
+            for (_pivot, basis_row) in basis.iter_mut() {
+                let factor = basis_row[pivot].clone();
+                if !factor.is_zero() {
+                    for (x, y) in basis_row.iter_mut().zip(&row) {
+                        *x -= &factor * y;
+                    }
+                }
+            }
+            basis.push((pivot, row));
+            if basis.len() == 6 {
+                debug!("rock pinned down by the first {} hailstones", j + 1);
+                break 'pairs;
+            }
+        }
+    }
+    if basis.len() < 6 {
+        return Err("the hailstones don't pin down a single rock");
+    }
+
+    let mut solution: Vec<BigRational> = vec![BigRational::zero(); 6];
+    for (pivot, row) in basis {
+        solution[pivot] = row[6].clone();
+    }
+    let position: Vector = [0, 1, 2].map(|i| solution[i].clone());
+    let velocity: Vector = [3, 4, 5].map(|i| solution[i].clone());
+
+    // The equations only say what the rock has to be if there is one, so check it really does hit
+    // everything.
+    for hailstone in hailstones {
+        let Some(time) = time_of_hit(&position, &velocity, hailstone) else {
+            return Err("no rock hits every hailstone");
+        };
+        trace!(?hailstone.position, %time, "rock hits hailstone");
+    }
+    if !solution.iter().all(|x| x.is_integer()) {
+        return Err("the rock would have to be thrown from a fractional position");
+    }
+    Ok(Rock {
+        position: position.map(|x| x.to_integer()),
+        velocity: velocity.map(|x| x.to_integer()),
+    })
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Hailstone>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(hailstones: &Self::Input) -> Answer {
+        let area = TestArea::square(200_000_000_000_000, 400_000_000_000_000);
+        count_intersections_in_test_area(hailstones, &area).into()
+    }
+
+    fn part2(hailstones: &Self::Input) -> Answer {
+        // Originally done in Python, with bounds on the rock's velocity guessed in a notebook - see
+        // notes. This does it properly.
+        match throw_rock(hailstones) {
+            Ok(rock) => rock.position.into_iter().sum::<BigInt>().into(),
+            Err(e) => Answer::Failed(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+
+    #[test]
+    fn parses_example() {
+        let hailstones = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(hailstones.len(), 5);
+        assert_eq!(hailstones[0].position, (19, 13, 30));
+        assert_eq!(hailstones[0].velocity, (-2, 1, -2));
+    }
+
+    #[test]
+    fn part1_example() {
+        // The example uses a much smaller test area.
+        let hailstones = Day24::parse(EXAMPLE).unwrap();
+        let area = TestArea::square(7, 27);
+        assert_eq!(count_intersections_in_test_area(&hailstones, &area), 2);
+    }
+
+    fn point(coordinates: &[(i64, i64)]) -> Point {
+        coordinates
+            .iter()
+            .map(|(n, d)| BigRational::new((*n).into(), (*d).into()))
+            .collect()
+    }
+
+    fn hailstones(s: &str) -> Vec<Hailstone> {
+        Day24::parse(&s.replace(" / ", "\n")).unwrap()
+    }
+
+    #[test]
+    fn classifies_crossings() {
+        let h = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            crossing_ignoring_z(&h[0], &h[1]),
+            At(point(&[(43, 3), (46, 3)]))
+        );
+        assert_eq!(crossing_ignoring_z(&h[0], &h[4]), InPast);
+        assert_eq!(crossing_ignoring_z(&h[1], &h[2]), Parallel);
+
+        // Paths along the same line, going the same way, towards each other and away from each
+        // other.
+        let h = hailstones("0, 0, 0 @ 1, 1, 0 / 2, 2, 5 @ 2, 2, 0");
+        let stretch = Stretch {
+            start: point(&[(2, 1), (2, 1)]),
+            direction: point(&[(1, 1), (1, 1)]),
+            length: None,
+        };
+        assert_eq!(crossing_ignoring_z(&h[0], &h[1]), Overlapping(stretch));
+        let h = hailstones("0, 0, 0 @ 1, 1, 0 / 4, 4, 0 @ -3, -3, 0");
+        let stretch = Stretch {
+            start: point(&[(0, 1), (0, 1)]),
+            direction: point(&[(1, 1), (1, 1)]),
+            length: Some(BigRational::from_integer(4.into())),
+        };
+        assert_eq!(crossing_ignoring_z(&h[0], &h[1]), Overlapping(stretch));
+        let h = hailstones("0, 0, 0 @ -1, -1, 0 / 4, 4, 0 @ 1, 1, 0");
+        assert_eq!(crossing_ignoring_z(&h[0], &h[1]), InPast);
+
+        // A hailstone which is only moving in z sits still in x and y.
+        let h = hailstones("3, 1, 0 @ 0, 0, 1 / 1, 1, 0 @ 1, 0, 0 / 0, 0, 0 @ 1, 0, 0");
+        let stretch = Stretch {
+            start: point(&[(3, 1), (1, 1)]),
+            direction: point(&[(1, 1), (0, 1)]),
+            length: Some(BigRational::zero()),
+        };
+        assert_eq!(crossing_ignoring_z(&h[0], &h[1]), Overlapping(stretch));
+        assert_eq!(crossing_ignoring_z(&h[0], &h[2]), Parallel);
+    }
+
+    #[test]
+    fn classifies_crossings_in_3d() {
+        let h = hailstones("0, 0, 0 @ 1, 0, 0 / 5, -5, 0 @ 0, 1, 0 / 5, -5, 1 @ 0, 1, 0");
+        assert_eq!(
+            crossing_in_3d(&h[0], &h[1]),
+            At(point(&[(5, 1), (0, 1), (0, 1)]))
+        );
+        assert_eq!(crossing_in_3d(&h[0], &h[2]), Skew);
+        // They still cross if you ignore z.
+        assert_eq!(
+            crossing_ignoring_z(&h[0], &h[2]),
+            At(point(&[(5, 1), (0, 1)]))
+        );
+
+        // In the example, the rock's path crosses each hailstone's.
+        let h = Day24::parse(&format!("{}24, 13, 10 @ -3, 1, 2\n", EXAMPLE)).unwrap();
+        for hailstone in &h[..5] {
+            assert!(matches!(crossing_in_3d(hailstone, &h[5]), At(_)));
+        }
+    }
+
+    #[test]
+    fn counts_overlapping_paths_in_the_test_area() {
+        // The paths overlap from (0, 0) to (4, 4), which only touches the first test area.
+        let h = hailstones("0, 0, 0 @ 1, 1, 0 / 4, 4, 0 @ -3, -3, 0");
+        assert_eq!(
+            count_intersections_in_test_area(&h, &TestArea::square(4, 10)),
+            1
+        );
+        assert_eq!(
+            count_intersections_in_test_area(&h, &TestArea::square(5, 10)),
+            0
+        );
+        let area = TestArea {
+            x: (-10, 1),
+            y: (2, 3),
+        };
+        assert_eq!(count_intersections_in_test_area(&h, &area), 0);
+    }
+
+    #[test]
+    fn part1_example_with_other_test_areas() {
+        let hailstones = Day24::parse(EXAMPLE).unwrap();
+        // Only the crossing at (14.333, 15.333) is in this one.
+        let area = TestArea {
+            x: (7, 15),
+            y: (15, 16),
+        };
+        assert_eq!(count_intersections_in_test_area(&hailstones, &area), 1);
+        // And the one at (6.2, 19.4) is in this one too.
+        let area = TestArea::square(0, 100);
+        assert_eq!(count_intersections_in_test_area(&hailstones, &area), 3);
+    }
+
+    #[test]
+    fn part2_example() {
+        let hailstones = Day24::parse(EXAMPLE).unwrap();
+        let rock = throw_rock(&hailstones).unwrap();
+        assert_eq!(rock.position, [24, 13, 10].map(BigInt::from));
+        assert_eq!(rock.velocity, [-3, 1, 2].map(BigInt::from));
+        assert_eq!(Day24::part2(&hailstones), Answer::Integer(47));
+    }
+
+    #[test]
+    fn part2_is_exact_for_big_numbers() {
+        // Positions near the real input's, where products of them lose precision in an f64. The
+        // rock starts at (315_345_712_843_217, 247_184_652_341_993, 381_467_223_915_131) with
+        // velocity (-123, 45, 217).
+        let s = "\
+97_074_107_684_090, 338_048_849_964_797, 529_615_372_213_181 @ 98, -47, 67
+305_962_996_878_305, 231_999_467_293_517, 371_714_137_583_183 @ -47, 168, 296
+344_790_157_287_632, 224_962_430_119_793, 421_467_223_915_091 @ -176, 85, 145
+307_617_394_915_607, 247_750_139_019_623, 381_781_383_180_481 @ 123, 27, 207
+";
+        let hailstones = Day24::parse(&s.replace('_', "")).unwrap();
+        assert_eq!(
+            Day24::part2(&hailstones),
+            Answer::Integer(315_345_712_843_217 + 247_184_652_341_993 + 381_467_223_915_131)
+        );
+    }
+
+    #[test]
+    fn part2_can_fail() {
+        // The example with the last hailstone moved over a bit, so the rock misses it.
+        let s = EXAMPLE.replace("20, 19, 15", "20, 19, 16");
+        let hailstones = Day24::parse(&s).unwrap();
+        assert_eq!(throw_rock(&hailstones), Err("no rock hits every hailstone"));
+
+        // Hailstones which are all moving in parallel don't give enough equations.
+        let hailstones =
+            Day24::parse("0, 0, 0 @ 1, 0, 0\n0, 1, 0 @ 1, 0, 0\n0, 3, 1 @ 1, 0, 0").unwrap();
+        assert_eq!(
+            throw_rock(&hailstones),
+            Err("the hailstones don't pin down a single rock")
+        );
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day24.txt and answers/day24.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day24>(24);
+    }
+}
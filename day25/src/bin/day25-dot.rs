@@ -0,0 +1,21 @@
+// Prints the wiring diagram as a Graphviz graph with the minimum cut highlighted, to compare against
+// the picture in the puzzle:
+//
+//     cargo run --release -p day25 --bin day25-dot -- inputs/day25.txt | neato -Tsvg > day25.svg
+
+use aoc_common::parse::or_exit;
+use aoc_common::solution::Solution;
+use day25::{stoer_wagner, to_dot, Day25};
+
+fn main() {
+    let args = aoc_common::input::from_args(25);
+    aoc_common::logging::init(args.verbosity);
+    let graph = or_exit(Day25::parse(&args.input));
+
+    let cut = stoer_wagner(&graph);
+    match &cut {
+        Some(cut) => eprintln!("Cutting {} wires: {:?}", cut.weight(), cut.wires),
+        None => eprintln!("There aren't enough components to cut in two"),
+    }
+    print!("{}", to_dot(&graph, cut.as_ref()));
+}
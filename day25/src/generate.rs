@@ -0,0 +1,98 @@
+use crate::Day25;
+use aoc_common::generate::{distinct_names, Generate};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{BTreeMap, BTreeSet};
+
+// Joins the [nodes] into a ring where every node is also joined to the ones 2 away, so it takes
+// at least 4 wires to split it up, and then adds some more wires at random.
+fn half(rng: &mut StdRng, nodes: &[usize], density: f64, edges: &mut BTreeSet<(usize, usize)>) {
+    let mut add = |a: usize, b: usize| {
+        if a != b {
+            edges.insert((a.min(b), a.max(b)));
+        }
+    };
+    let n = nodes.len();
+    for i in 0..n {
+        add(nodes[i], nodes[(i + 1) % n]);
+        add(nodes[i], nodes[(i + 2) % n]);
+        if rng.gen_bool(density) {
+            add(nodes[i], nodes[rng.gen_range(0..n)]);
+        }
+    }
+}
+
+// The wiring diagram, along with the sizes of the two groups that the 3 wires split it into.
+fn diagram(rng: &mut StdRng, size: usize, density: f64) -> (String, usize, usize) {
+    let size = size.max(10);
+    let size1 = rng.gen_range(size / 3..=size - size / 3).clamp(5, size - 5);
+    let mut nodes: Vec<usize> = (0..size).collect();
+    nodes.shuffle(rng);
+    let (nodes1, nodes2) = nodes.split_at(size1);
+
+    let mut edges = BTreeSet::new();
+    half(rng, nodes1, density, &mut edges);
+    half(rng, nodes2, density, &mut edges);
+    // Each half is still joined up with 4 wires to spare, so these are the only 3 wires that
+    // split the whole thing in two.
+    let mut cut = BTreeSet::new();
+    while cut.len() < 3 {
+        let a = nodes1[rng.gen_range(0..nodes1.len())];
+        let b = nodes2[rng.gen_range(0..nodes2.len())];
+        cut.insert((a.min(b), a.max(b)));
+    }
+    edges.extend(cut);
+
+    // Every wire only gets listed once, on the line for one of its ends.
+    let names = distinct_names(rng, size, 3, &[]);
+    let mut lines: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for (a, b) in edges {
+        let (from, to) = if rng.gen_bool(0.5) { (a, b) } else { (b, a) };
+        lines.entry(from).or_default().push(&names[to]);
+    }
+    let mut lines: Vec<String> = lines
+        .into_iter()
+        .map(|(from, to)| format!("{}: {}", names[from], to.join(" ")))
+        .collect();
+    lines.shuffle(rng);
+
+    (lines.join("\n") + "\n", size1, size - size1)
+}
+
+impl Generate for Day25 {
+    const SIZE: &'static str = "components";
+    const DEFAULT_SIZE: usize = 1500;
+    // Every component has a different 3 letter name.
+    const MAX_SIZE: usize = 26 * 26 * 26;
+    const DENSITY: Option<&'static str> = Some("a component having an extra wire");
+    const DEFAULT_DENSITY: f64 = 0.5;
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String {
+        diagram(rng, size, density).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solution::{Answer, Solution};
+    use rand::SeedableRng;
+
+    #[test]
+    fn finds_the_planted_cut() {
+        for seed in 0..10 {
+            let (s, size1, size2) = diagram(&mut StdRng::seed_from_u64(seed), 30, 0.5);
+            let input = Day25::parse(&s).unwrap();
+            assert_eq!(Day25::part1(&input), Answer::from(size1 * size2));
+            // Karger-Stein is slow in debug builds, so only check it on a few.
+            if seed < 3 {
+                let mut rng = StdRng::seed_from_u64(seed);
+                assert_eq!(
+                    Some(crate::karger_stein(&input, &mut rng, 20)),
+                    crate::stoer_wagner(&input)
+                );
+            }
+        }
+    }
+}
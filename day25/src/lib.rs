@@ -0,0 +1,691 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use rand::prelude::*;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use tracing::{debug, trace};
+
+mod generate;
+
+#[derive(Debug)]
+pub struct Graph {
+    edges: HashMap<String, Vec<String>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Index(usize);
+
+#[derive(Debug)]
+struct KargerState {
+    index_to_strings: HashMap<Index, HashSet<String>>,
+    edges: HashMap<Index, HashMap<Index, usize>>,
+}
+
+// Gives up after [max_attempts] tries without finding a cut of [target_min_cut] wires. The
+// randomness all comes from [rng], so the same seed always gives the same cut.
+pub fn karger(
+    g: &Graph,
+    rng: &mut StdRng,
+    target_min_cut: usize,
+    max_attempts: usize,
+) -> Option<MinCut> {
+    // Until out of attempts:
+    // Create a new structure
+    // Repeatedly contract an edge. Move weight onto other vertices.
+    // Until your state has 2 vertices. Then check the weight of the edge between them.
+
+    let graph = g.multigraph();
+    if let Some(side) = already_split(&graph) {
+        return (target_min_cut == 0).then(|| g.cut(&side));
+    }
+    if graph.groups.len() < 2 {
+        return None;
+    }
+
+    for attempts in 1..=max_attempts {
+        // Set up Karger State. Number the components in order, so the seed is all that matters.
+        let string_to_index: HashMap<String, Index> = g
+            .names()
+            .into_iter()
+            .enumerate()
+            .map(|(i, s)| (s.clone(), Index(i)))
+            .collect();
+
+        let index_to_strings: HashMap<Index, HashSet<String>> = string_to_index
+            .iter()
+            .map(|(string, index)| (index.clone(), vec![string.clone()].into_iter().collect()))
+            .collect();
+
+        let edges: HashMap<Index, HashMap<Index, usize>> = g
+            .edges
+            .iter()
+            .map(|(s, neighbours)| {
+                let i = string_to_index.get(s).unwrap().clone();
+                let neighbours = neighbours
+                    .iter()
+                    .map(|s| {
+                        let i = string_to_index.get(s).unwrap().clone();
+                        (i, 1)
+                    })
+                    .collect();
+                (i, neighbours)
+            })
+            .collect();
+
+        let mut karger_state = KargerState {
+            index_to_strings,
+            edges,
+        };
+
+        // While it has size >2: Repeatedly try and contract an edge.
+
+        while karger_state.edges.len() > 2 {
+            // Pick a random edge.
+            let mut keys: Vec<&Index> = karger_state.edges.keys().collect();
+            keys.sort_by_key(|i| i.0);
+
+            let v1: Index = keys[rng.gen_range(0..keys.len())].clone();
+            let n1 = &karger_state.edges[&v1];
+
+            let mut keys: Vec<&Index> = n1.keys().collect();
+            keys.sort_by_key(|i| i.0);
+            let v2: Index = keys[rng.gen_range(0..keys.len())].clone();
+
+            // Contract (v1, v2) by turning v2 into v1.
+            let strings2 = karger_state.index_to_strings.remove(&v2).unwrap();
+            karger_state
+                .index_to_strings
+                .get_mut(&v1)
+                .unwrap()
+                .extend(strings2);
+
+            let old_connections_to_v2 = karger_state.edges.remove(&v2).unwrap();
+
+            // Fix up v1.
+            for (edge, size) in old_connections_to_v2 {
+                if edge != v1 {
+                    *karger_state
+                        .edges
+                        .get_mut(&v1)
+                        .unwrap()
+                        .entry(edge)
+                        .or_insert(0) += size;
+                }
+            }
+
+            for (index, edges) in &mut karger_state.edges {
+                let old_edges_to_v2 = edges.remove(&v2);
+
+                if let Some(old_edges_to_v2) = old_edges_to_v2 {
+                    if index != &v1 {
+                        // Move the edges over to v1.
+                        *edges.entry(v1.clone()).or_insert(0) += old_edges_to_v2;
+                    }
+                }
+            }
+        }
+
+        // Have we hit our target min_cut?
+        let KargerState {
+            index_to_strings,
+            edges,
+        } = karger_state;
+        let keys: Vec<Index> = edges.keys().cloned().collect();
+        assert!(keys.len() == 2);
+
+        let score = edges[&keys[0]][&keys[1]];
+        if score == target_min_cut {
+            debug!(
+                "randomized algorithm found a min cut after {} attempts",
+                attempts
+            );
+            let names = g.names();
+            let side: Vec<usize> = index_to_strings[&keys[0]]
+                .iter()
+                .map(|s| names.binary_search(&s).unwrap())
+                .collect();
+            return Some(g.cut(&side));
+        }
+    }
+    debug!(
+        "randomized algorithm gave up after {} attempts",
+        max_attempts
+    );
+    None
+}
+
+// A cut which splits the components into two groups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut {
+    // The wires to disconnect, with the names at each end in order, and sorted.
+    pub wires: Vec<(String, String)>,
+    // The names of the components on each side, sorted. The first side is the one with the
+    // component that comes first alphabetically, so the same cut always comes out the same way.
+    pub sides: (Vec<String>, Vec<String>),
+}
+
+impl MinCut {
+    // How many wires there are to disconnect - wires listed more than once in the input count more
+    // than once.
+    pub fn weight(&self) -> usize {
+        self.wires.len()
+    }
+}
+
+// The wiring diagram with the components numbered (in alphabetical order, so that nothing depends
+// on the order of a HashMap), and all the wires between two vertices merged into one weighted edge.
+// Each vertex starts off as one component, and the min cut algorithms merge them together.
+#[derive(Debug, Clone)]
+struct Multigraph {
+    // The original components which have been merged into each vertex.
+    groups: Vec<Vec<usize>>,
+    // (a, b, weight), with a < b.
+    edges: Vec<(usize, usize, usize)>,
+}
+
+impl Graph {
+    fn names(&self) -> Vec<&String> {
+        let mut names: Vec<&String> = self.edges.keys().collect();
+        names.sort();
+        names
+    }
+
+    // Each wire once, as (a, b) with a < b. Wires listed more than once in the input come up more
+    // than once here too.
+    fn wires(&self) -> Vec<(&String, &String)> {
+        let mut wires = vec![];
+        for (a, neighbours) in &self.edges {
+            for b in neighbours {
+                if a < b {
+                    wires.push((a, b));
+                }
+            }
+        }
+        wires.sort();
+        wires
+    }
+
+    fn multigraph(&self) -> Multigraph {
+        let names = self.names();
+        let index: HashMap<&String, usize> =
+            names.iter().enumerate().map(|(i, s)| (*s, i)).collect();
+        let mut weights: HashMap<(usize, usize), usize> = HashMap::new();
+        for (a, b) in self.wires() {
+            *weights.entry((index[a], index[b])).or_insert(0) += 1;
+        }
+        let mut edges: Vec<(usize, usize, usize)> =
+            weights.into_iter().map(|((a, b), w)| (a, b, w)).collect();
+        edges.sort();
+        Multigraph {
+            groups: (0..names.len()).map(|i| vec![i]).collect(),
+            edges,
+        }
+    }
+
+    // The cut between the components numbered in [side] and the rest.
+    fn cut(&self, side: &[usize]) -> MinCut {
+        let names = self.names();
+        let mut in_side = vec![false; names.len()];
+        for i in side {
+            in_side[*i] = true;
+        }
+        // Make sure the first component is on the first side.
+        if !in_side.is_empty() && !in_side[0] {
+            in_side.iter_mut().for_each(|x| *x = !*x);
+        }
+        let in_side: HashMap<&String, bool> = names.iter().copied().zip(in_side).collect();
+
+        let wires: Vec<(String, String)> = self
+            .wires()
+            .into_iter()
+            .filter(|(a, b)| in_side[a] != in_side[b])
+            .map(|(a, b)| (a.clone(), b.clone()))
+            .collect();
+        let (side1, side2): (Vec<&String>, Vec<&String>) =
+            names.into_iter().partition(|name| in_side[name]);
+        MinCut {
+            wires,
+            sides: (
+                side1.into_iter().cloned().collect(),
+                side2.into_iter().cloned().collect(),
+            ),
+        }
+    }
+}
+
+// Which component of the graph each vertex is in, as the lowest vertex in that component.
+fn connected_components(n: usize, edges: &[(usize, usize, usize)]) -> Vec<usize> {
+    let mut parent: Vec<usize> = (0..n).collect();
+    for (a, b, _weight) in edges {
+        union(&mut parent, *a, *b);
+    }
+    (0..n).map(|v| find(&mut parent, v)).collect()
+}
+
+fn find(parent: &mut [usize], mut v: usize) -> usize {
+    while parent[v] != v {
+        parent[v] = parent[parent[v]];
+        v = parent[v];
+    }
+    v
+}
+
+// Returns whether a and b were in different sets before.
+fn union(parent: &mut [usize], a: usize, b: usize) -> bool {
+    let (a, b) = (find(parent, a), find(parent, b));
+    parent[a.max(b)] = a.min(b);
+    a != b
+}
+
+// If the wiring is already in more than one piece, there's nothing to cut: the first piece goes on
+// one side, and everything else on the other.
+fn already_split(graph: &Multigraph) -> Option<Vec<usize>> {
+    let components = connected_components(graph.groups.len(), &graph.edges);
+    if components.iter().all(|c| *c == 0) {
+        return None;
+    }
+    let side = (0..graph.groups.len()).filter(|v| components[*v] == 0);
+    Some(side.flat_map(|v| graph.groups[v].clone()).collect())
+}
+
+// The Stoer-Wagner algorithm. This is deterministic, and finds a minimum cut without needing to
+// know how big it is in advance, unlike my original Karger loop.
+//
+// Each phase adds the vertices one at a time, always picking the one most tightly connected to
+// those added so far. The last vertex added, cut off from everything else, is a minimum cut between
+// it and the second to last one - so either that's a minimum cut overall, or those two are on the
+// same side of one and can be merged. n - 1 phases later, everything's merged and the best of the
+// phases' cuts is the answer.
+pub fn stoer_wagner(g: &Graph) -> Option<MinCut> {
+    let mut graph = g.multigraph();
+    if let Some(side) = already_split(&graph) {
+        return Some(g.cut(&side));
+    }
+    let n = graph.groups.len();
+
+    let mut neighbours: Vec<HashMap<usize, usize>> = vec![HashMap::new(); n];
+    for (a, b, weight) in &graph.edges {
+        neighbours[*a].insert(*b, *weight);
+        neighbours[*b].insert(*a, *weight);
+    }
+    let mut merged = vec![false; n];
+    let mut best: Option<(usize, Vec<usize>)> = None;
+
+    for phase in 1..n {
+        let start = (0..n).find(|v| !merged[*v]).unwrap();
+        let mut connection = vec![0; n];
+        let mut added = vec![false; n];
+        // Entries go stale when a vertex's connection goes up, and get skipped.
+        let mut queue = BinaryHeap::from([(0, start)]);
+        let mut order = vec![];
+        while let Some((weight, v)) = queue.pop() {
+            if added[v] || weight != connection[v] {
+                continue;
+            }
+            added[v] = true;
+            order.push(v);
+            for (w, weight) in &neighbours[v] {
+                if !added[*w] {
+                    connection[*w] += weight;
+                    queue.push((connection[*w], *w));
+                }
+            }
+        }
+        let [.., s, t] = order[..] else {
+            unreachable!("Every phase has at least two vertices left");
+        };
+
+        let cut_of_the_phase = connection[t];
+        trace!(phase, cut_of_the_phase, "Stoer-Wagner phase");
+        if best
+            .as_ref()
+            .is_none_or(|(weight, _side)| cut_of_the_phase < *weight)
+        {
+            best = Some((cut_of_the_phase, graph.groups[t].clone()));
+        }
+
+        // Merge t into s.
+        let group = std::mem::take(&mut graph.groups[t]);
+        graph.groups[s].extend(group);
+        merged[t] = true;
+        for (w, weight) in std::mem::take(&mut neighbours[t]) {
+            neighbours[w].remove(&t);
+            if w != s {
+                *neighbours[s].entry(w).or_insert(0) += weight;
+                *neighbours[w].entry(s).or_insert(0) += weight;
+            }
+        }
+    }
+
+    // There's nothing to cut with fewer than two components.
+    let (weight, side) = best?;
+    debug!(weight, "Stoer-Wagner found a min cut");
+    Some(g.cut(&side))
+}
+
+// Contracts random edges, with a chance proportional to their weight, until there are only
+// [vertices] vertices left. The graph has to be connected.
+//
+// Contracting random edges one at a time is the same as putting all the edges in a random order
+// and contracting them in that order, skipping any that have already been contracted - like
+// Kruskal's algorithm. For weighted edges, giving each one an exponentially distributed key with
+// rate equal to its weight and sorting by that does the same thing.
+fn contract(graph: &Multigraph, vertices: usize, rng: &mut StdRng) -> Multigraph {
+    let n = graph.groups.len();
+    let mut keyed: Vec<(f64, usize)> = graph
+        .edges
+        .iter()
+        .enumerate()
+        .map(|(i, (_a, _b, weight))| (-(1.0 - rng.gen::<f64>()).ln() / *weight as f64, i))
+        .collect();
+    keyed.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    let mut parent: Vec<usize> = (0..n).collect();
+    let mut remaining = n;
+    for (_key, i) in keyed {
+        if remaining <= vertices {
+            break;
+        }
+        let (a, b, _weight) = graph.edges[i];
+        if union(&mut parent, a, b) {
+            remaining -= 1;
+        }
+    }
+
+    // Renumber what's left, and merge the edges between the same pair of vertices.
+    let mut renumber = HashMap::new();
+    let mut groups: Vec<Vec<usize>> = vec![];
+    for v in 0..n {
+        let root = find(&mut parent, v);
+        let i = *renumber.entry(root).or_insert_with(|| {
+            groups.push(vec![]);
+            groups.len() - 1
+        });
+        groups[i].extend(&graph.groups[v]);
+    }
+    let mut weights: HashMap<(usize, usize), usize> = HashMap::new();
+    for (a, b, weight) in &graph.edges {
+        let a = renumber[&find(&mut parent, *a)];
+        let b = renumber[&find(&mut parent, *b)];
+        if a != b {
+            *weights.entry((a.min(b), a.max(b))).or_insert(0) += weight;
+        }
+    }
+    let mut edges: Vec<(usize, usize, usize)> =
+        weights.into_iter().map(|((a, b), w)| (a, b, w)).collect();
+    // Sorted, so the same seed always gives the same answer.
+    edges.sort();
+    Multigraph { groups, edges }
+}
+
+// One run of the recursive Karger-Stein algorithm, returning the weight of the cut it found and the
+// components on one side of it.
+fn karger_stein_run(graph: &Multigraph, rng: &mut StdRng) -> (usize, Vec<usize>) {
+    let n = graph.groups.len();
+    if n <= 6 {
+        let graph = contract(graph, 2, rng);
+        let weight = graph.edges.iter().map(|(_a, _b, weight)| weight).sum();
+        return (weight, graph.groups[0].clone());
+    }
+
+    // Contracting down to n / sqrt(2) vertices leaves a min cut intact at least half the time, so
+    // try that twice and take the better one.
+    let vertices = (1.0 + n as f64 / std::f64::consts::SQRT_2).ceil() as usize;
+    let first = karger_stein_run(&contract(graph, vertices, rng), rng);
+    let second = karger_stein_run(&contract(graph, vertices, rng), rng);
+    if first.0 <= second.0 {
+        first
+    } else {
+        second
+    }
+}
+
+// Karger's algorithm, sped up by Stein: rather than contracting all the way down to 2 vertices and
+// starting again from scratch if that didn't work, it shares the early contractions (which are
+// unlikely to go wrong) between lots of attempts at the later ones. Each run finds a minimum cut
+// with probability at least about 1 / log(n), so it's worth doing (log n)^2 or so of them. The
+// randomness all comes from [rng], so the same seed always gives the same cut.
+pub fn karger_stein(g: &Graph, rng: &mut StdRng, runs: usize) -> MinCut {
+    let graph = g.multigraph();
+    if let Some(side) = already_split(&graph) {
+        return g.cut(&side);
+    }
+    assert!(
+        graph.groups.len() >= 2,
+        "Need at least two components to cut"
+    );
+
+    let (weight, side) = (0..runs.max(1))
+        .map(|_run| karger_stein_run(&graph, rng))
+        .min_by_key(|(weight, _side)| *weight)
+        .unwrap();
+    debug!(weight, runs, "Karger-Stein found a cut");
+    g.cut(&side)
+}
+
+// The wiring diagram in Graphviz's DOT language, to check a cut against the picture in the puzzle.
+// The components on each side of the cut are coloured differently, and the wires to disconnect are
+// thick and red. The puzzle's picture looks like neato's layout, e.g. neato -Tsvg.
+pub fn to_dot(g: &Graph, cut: Option<&MinCut>) -> String {
+    let mut result = String::from("graph wiring {\n    node [shape=circle, style=filled];\n");
+    if let Some(cut) = cut {
+        for (side, colour) in [(&cut.sides.0, "lightblue"), (&cut.sides.1, "orange")] {
+            for name in side {
+                result += &format!("    {} [fillcolor={}];\n", name, colour);
+            }
+        }
+    } else {
+        for name in g.names() {
+            result += &format!("    {};\n", name);
+        }
+    }
+    for (a, b) in g.wires() {
+        let is_cut = cut.is_some_and(|cut| cut.wires.contains(&(a.clone(), b.clone())));
+        if is_cut {
+            result += &format!("    {} -- {} [color=red, penwidth=4];\n", a, b);
+        } else {
+            result += &format!("    {} -- {};\n", a, b);
+        }
+    }
+    result + "}\n"
+}
+
+fn parse(s: &str) -> Result<Graph, ParseError> {
+    let mut result = HashMap::new();
+
+    for line in s.trim().lines() {
+        let (from, to) = parse::split_once(line, ": ").map_err(|e| e.locate(s))?;
+        let from = from.to_string();
+        let to: Vec<String> = to
+            .split_whitespace()
+            .map(|s| s.trim().to_string())
+            .collect();
+
+        for s in &to {
+            result.entry(s.clone()).or_insert(vec![]).push(from.clone());
+        }
+        result.entry(from).or_insert(vec![]).extend(to);
+    }
+    Ok(Graph { edges: result })
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Graph;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(graph: &Self::Input) -> Answer {
+        // This used to loop on [karger] until it found a cut of 3 wires, but Stoer-Wagner gets
+        // there without being told.
+        let Some(cut) = stoer_wagner(graph) else {
+            return Answer::Failed("there aren't enough components to cut in two".to_string());
+        };
+        if cut.weight() != 3 {
+            return Answer::Failed(format!("the smallest cut is {} wires, not 3", cut.weight()));
+        }
+        (cut.sides.0.len() * cut.sides.1.len()).into()
+    }
+
+    fn part2(_graph: &Self::Input) -> Answer {
+        Answer::Unsolved("there's no puzzle for part 2 on the last day")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+
+    #[test]
+    fn parses_example() {
+        let graph = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!(graph.edges.len(), 15);
+        // Edges go both ways, even though the input only lists them once.
+        assert!(graph.edges["jqt"].contains(&"ntq".to_string()));
+        assert!(graph.edges["ntq"].contains(&"jqt".to_string()));
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!(Day25::part1(&input), Answer::Integer(54));
+    }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn example_cut() -> MinCut {
+        let wires = [("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")];
+        MinCut {
+            wires: wires
+                .into_iter()
+                .map(|(a, b)| (a.to_string(), b.to_string()))
+                .collect(),
+            sides: (
+                names(&["bvb", "hfx", "jqt", "ntq", "rhn", "xhk"]),
+                names(&[
+                    "cmg", "frs", "lhk", "lsr", "nvd", "pzl", "qnr", "rsh", "rzs",
+                ]),
+            ),
+        }
+    }
+
+    #[test]
+    fn stoer_wagner_example() {
+        let input = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!(stoer_wagner(&input), Some(example_cut()));
+    }
+
+    #[test]
+    fn karger_stein_example() {
+        let input = Day25::parse(EXAMPLE).unwrap();
+        for seed in 0..5 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            assert_eq!(karger_stein(&input, &mut rng, 10), example_cut());
+        }
+    }
+
+    #[test]
+    fn karger_stein_is_reproducible() {
+        // With only one run, it often doesn't find the best cut - but it always finds the same one
+        // for the same seed.
+        let input = Day25::parse(EXAMPLE).unwrap();
+        for seed in 0..10 {
+            let cut = karger_stein(&input, &mut StdRng::seed_from_u64(seed), 1);
+            assert_eq!(
+                karger_stein(&input, &mut StdRng::seed_from_u64(seed), 1),
+                cut
+            );
+        }
+    }
+
+    #[test]
+    fn cuts_nothing_if_already_split() {
+        let input = Day25::parse("a: b\nc: d e\n").unwrap();
+        let cut = MinCut {
+            wires: vec![],
+            sides: (names(&["a", "b"]), names(&["c", "d", "e"])),
+        };
+        assert_eq!(stoer_wagner(&input), Some(cut.clone()));
+        assert_eq!(karger_stein(&input, &mut StdRng::seed_from_u64(0), 1), cut);
+    }
+
+    #[test]
+    fn counts_repeated_wires() {
+        // a and b are joined twice, so it's cheaper to cut c off.
+        let input = Day25::parse("a: b c\nb: a c\n").unwrap();
+        let cut = stoer_wagner(&input).unwrap();
+        assert_eq!(cut.sides, (names(&["a", "b"]), names(&["c"])));
+        assert_eq!(cut.wires.len(), 2);
+        assert_eq!(cut.weight(), 2);
+    }
+
+    #[test]
+    fn nothing_to_cut() {
+        let input = Day25::parse("").unwrap();
+        assert_eq!(stoer_wagner(&input), None);
+        assert!(matches!(Day25::part1(&input), Answer::Failed(_)));
+    }
+
+    #[test]
+    fn karger_example() {
+        let input = Day25::parse(EXAMPLE).unwrap();
+        for seed in 0..5 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            assert_eq!(karger(&input, &mut rng, 3, 1000), Some(example_cut()));
+        }
+        // There's no cut that small, so it gives up.
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(karger(&input, &mut rng, 2, 100), None);
+    }
+
+    #[test]
+    fn exports_the_cut() {
+        let input = Day25::parse("a: b c\nb: c\nc: d\n").unwrap();
+        let cut = stoer_wagner(&input).unwrap();
+        assert_eq!(cut.sides, (names(&["a", "b", "c"]), names(&["d"])));
+        assert_eq!(
+            to_dot(&input, Some(&cut)),
+            "\
+graph wiring {
+    node [shape=circle, style=filled];
+    a [fillcolor=lightblue];
+    b [fillcolor=lightblue];
+    c [fillcolor=lightblue];
+    d [fillcolor=orange];
+    a -- b;
+    a -- c;
+    b -- c;
+    c -- d [color=red, penwidth=4];
+}
+"
+        );
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day25.txt and answers/day25.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day25>(25);
+    }
+}
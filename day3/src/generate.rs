@@ -0,0 +1,42 @@
+use crate::Day3;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '%', '=', '-', '&'];
+
+impl Generate for Day3 {
+    const SIZE: &'static str = "rows and columns";
+    const DEFAULT_SIZE: usize = 140;
+    const DENSITY: Option<&'static str> = Some("a number starting in each cell");
+    const DEFAULT_DENSITY: f64 = 0.15;
+
+    fn generate(rng: &mut StdRng, size: usize, density: f64) -> String {
+        let mut result = String::new();
+        for _ in 0..size {
+            let mut row = String::new();
+            while row.len() < size {
+                let space = size - row.len();
+                if rng.gen_bool(density) {
+                    // A number, with a gap after it so it doesn't run into the next one.
+                    let digits = rng.gen_range(1..=3).min(space);
+                    row.push_str(
+                        &rng.gen_range(10usize.pow(digits as u32 - 1)..10usize.pow(digits as u32))
+                            .to_string(),
+                    );
+                    if row.len() < size {
+                        row.push('.');
+                    }
+                } else if rng.gen_bool(density / 2.0) {
+                    row.push(*SYMBOLS.choose(rng).unwrap());
+                } else {
+                    row.push('.');
+                }
+            }
+            result.push_str(&row);
+            result.push('\n');
+        }
+        result
+    }
+}
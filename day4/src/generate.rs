@@ -0,0 +1,59 @@
+use crate::Day4;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+const WINNING_NUMBERS: usize = 10;
+const OUR_NUMBERS: usize = 25;
+
+impl Generate for Day4 {
+    const SIZE: &'static str = "cards";
+    const DEFAULT_SIZE: usize = 200;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        let format_numbers = |numbers: &[usize]| -> String {
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        // How many copies of each card we end up with. The number of copies can grow exponentially
+        // as we go down the table, so once a card has lots of copies it doesn't win any more - this
+        // keeps part 2's answer down around the size of the real ones.
+        let mut copies = vec![1usize; size + 1];
+        let mut result = String::new();
+        for card in 1..=size {
+            // 35 different numbers, split between the winning numbers and ours, except for the
+            // matches which are on both sides. Cards never win copies of cards past the end of the
+            // table.
+            let matches = if copies[card] > 10_000 {
+                0
+            } else {
+                rng.gen_range(0..=WINNING_NUMBERS.min(size - card))
+            };
+            for next in card + 1..=card + matches {
+                copies[next] += copies[card];
+            }
+            let numbers: Vec<usize> = index::sample(rng, 99, WINNING_NUMBERS + OUR_NUMBERS)
+                .into_iter()
+                .map(|n| n + 1)
+                .collect();
+            let winning = &numbers[..WINNING_NUMBERS];
+            let mut ours =
+                numbers[WINNING_NUMBERS..WINNING_NUMBERS + OUR_NUMBERS - matches].to_vec();
+            ours.extend(&winning[..matches]);
+            ours.shuffle(rng);
+
+            result.push_str(&format!(
+                "Card {:>3}: {} | {}\n",
+                card,
+                format_numbers(winning),
+                format_numbers(&ours)
+            ));
+        }
+        result
+    }
+}
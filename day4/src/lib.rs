@@ -0,0 +1,140 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::collections::HashSet;
+use tracing::trace;
+
+mod generate;
+
+#[derive(Debug)]
+pub struct Card {
+    pub winning_numbers: HashSet<usize>,
+    pub our_numbers: Vec<usize>,
+}
+
+fn parse_line(s: &str) -> Result<Card, ParseError> {
+    trace!("parsing {}", s);
+    let (_card, numbers) = parse::split_once(s, ":")?;
+    let (winning_numbers, our_numbers) = parse::split_once(numbers, " | ")?;
+
+    // Need split_whitespace because some numbers have two spaces
+    let winning_numbers = winning_numbers
+        .split_whitespace()
+        .map(parse::number)
+        .collect::<Result<_, _>>()?;
+    let our_numbers = our_numbers
+        .split_whitespace()
+        .map(parse::number)
+        .collect::<Result<_, _>>()?;
+
+    Ok(Card {
+        winning_numbers,
+        our_numbers,
+    })
+}
+
+fn number_of_winning_numbers(card: &Card) -> usize {
+    card.our_numbers
+        .iter()
+        .copied()
+        .filter(|x| card.winning_numbers.contains(x))
+        .count()
+}
+
+fn score(card: &Card) -> usize {
+    let number_of_winning_numbers = number_of_winning_numbers(card);
+    if number_of_winning_numbers == 0 {
+        return 0usize;
+    }
+    {
+        2usize.pow((number_of_winning_numbers - 1) as u32)
+    }
+}
+
+fn score_part2(cards: &[Card]) -> usize {
+    let mut number_of_cards: Vec<usize> = cards.iter().map(|_card| 1).collect();
+
+    for (index, card) in cards.iter().enumerate() {
+        let number_of_this_card = number_of_cards[index];
+        let n = number_of_winning_numbers(card);
+
+        // If we have n winning numbers, and M of this card, that gives us M copies of the next n cards.
+        // I haven't thought about overflow here - the problem is ambiguous.
+        for i in index + 1..=index + n {
+            number_of_cards[i] += number_of_this_card
+        }
+    }
+
+    number_of_cards.iter().sum()
+}
+
+fn parse(s: &str) -> Result<Vec<Card>, ParseError> {
+    s.trim()
+        .lines()
+        .map(parse_line)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(s))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(cards: &Self::Input) -> Answer {
+        cards.iter().map(score).sum::<usize>().into()
+    }
+
+    fn part2(cards: &Self::Input) -> Answer {
+        score_part2(cards).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn parses_example() {
+        let cards = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(cards.len(), 6);
+        assert_eq!(cards[0].winning_numbers.len(), 5);
+        assert_eq!(cards[0].our_numbers, vec![83, 86, 6, 31, 17, 9, 48, 53]);
+    }
+
+    #[test]
+    fn rejects_a_missing_separator() {
+        let e = Day4::parse("Card 1: 41 48 83 86 17 83 86").unwrap_err();
+        assert_eq!(e.expected(), "\" | \"");
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&input), Answer::Integer(13));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&input), Answer::Integer(30));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day04.txt and answers/day04.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day4>(4);
+    }
+}
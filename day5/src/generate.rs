@@ -0,0 +1,92 @@
+use crate::Day5;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+// Every number is below this, like in the real inputs.
+const LIMIT: usize = 1 << 32;
+const SEED_RANGES: usize = 10;
+
+impl Generate for Day5 {
+    const SIZE: &'static str = "ranges in each map";
+    const DEFAULT_SIZE: usize = 30;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        // Pairs of (start, length), for part 2.
+        let seeds: Vec<String> = (0..SEED_RANGES)
+            .flat_map(|_| {
+                let start = rng.gen_range(0..LIMIT - 1);
+                let length = rng.gen_range(1..=(LIMIT - start).min(LIMIT / 20));
+                [start.to_string(), length.to_string()]
+            })
+            .collect();
+        let mut result = format!("seeds: {}\n", seeds.join(" "));
+
+        // Like the real inputs, each map shuffles the pieces of 0..LIMIT around, so every number
+        // maps to a different one.
+        for categories in CATEGORIES.windows(2) {
+            let mut cuts: Vec<usize> = index::sample(rng, LIMIT - 1, size.max(1) - 1)
+                .into_iter()
+                .map(|cut| cut + 1)
+                .collect();
+            cuts.push(0);
+            cuts.push(LIMIT);
+            cuts.sort();
+            let pieces: Vec<(usize, usize)> =
+                cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+
+            let mut order: Vec<usize> = (0..pieces.len()).collect();
+            order.shuffle(rng);
+            let mut lines = vec![];
+            let mut destination = 0;
+            for i in order {
+                let (source, length) = pieces[i];
+                lines.push(format!("{} {} {}", destination, source, length));
+                destination += length;
+            }
+            lines.shuffle(rng);
+
+            result.push_str(&format!(
+                "\n{}-to-{} map:\n{}\n",
+                categories[0],
+                categories[1],
+                lines.join("\n")
+            ));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solution::Solution;
+    use rand::SeedableRng;
+
+    #[test]
+    fn generated_maps_can_be_composed_and_inverted() {
+        for seed in 0..10 {
+            let s = Day5::generate(&mut StdRng::seed_from_u64(seed), 20, 0.5);
+            let input = Day5::parse(&s).unwrap();
+            assert_eq!(input.soil_maps.len(), 7);
+            assert!(input.soil_maps.iter().all(|m| m.0.len() == 20));
+            // Composing the maps should give the same answer as going through them one by one.
+            assert_eq!(Day5::part2(&input), crate::solve2(&input).unwrap().into());
+            // And every map shuffles the numbers around, so the whole chain can be undone.
+            assert!(input.seed_to_location().invert().is_some());
+            assert!(input.soil_maps.iter().all(|m| m.check_bijection().is_ok()));
+        }
+    }
+}
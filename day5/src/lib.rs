@@ -0,0 +1,725 @@
+use aoc_common::intervals::IntervalSet;
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::ops::Range;
+use tracing::trace;
+
+mod generate;
+
+#[derive(Debug)]
+pub struct SoilMapOne {
+    pub input_start: usize,
+    pub output_start: usize,
+    pub length: usize,
+}
+
+#[derive(Debug)]
+pub struct SoilMap(pub Vec<SoilMapOne>);
+
+#[derive(Debug)]
+pub struct Problem {
+    pub input_seeds: Vec<usize>,
+    pub soil_maps: Vec<SoilMap>,
+}
+
+struct ApplyRangeResult {
+    mapped: Option<Range<usize>>,
+    // The vector might be length 0, 1 or 2, depending on how much of the range is mapped.
+    unmapped: Vec<Range<usize>>,
+}
+
+impl SoilMapOne {
+    fn apply(&self, v: usize) -> Option<usize> {
+        let SoilMapOne {
+            input_start,
+            output_start,
+            length,
+        } = self;
+        if v >= *input_start && v < *input_start + *length {
+            Some(output_start + v - *input_start)
+        } else {
+            None
+        }
+    }
+
+    // The single-element vectors of ranges below are deliberate, not a typo for a vector of numbers.
+    #[allow(clippy::single_range_in_vec_init)]
+    fn apply_range(&self, input_range: Range<usize>) -> ApplyRangeResult {
+        // 5 cases to deal with:
+        // (1) The range is fully outside of the map - if so, just return it.
+        // (2) The range is fully inside of the map.
+        // (3) The range is outside on the left, inside on the right.
+        // (4) The range is inside on the left, outside on the right.
+        // (5) The range spans the whole of the map, so we break it into 3 parts.
+        //
+        // I can probably combine these cases in some smarter way, but whatever.
+
+        let map_range = self.input_start..self.input_start + self.length;
+
+        fn shift(x: &SoilMapOne, range: Range<usize>) -> Range<usize> {
+            (range.start + x.output_start - x.input_start)
+                ..(range.end + x.output_start - x.input_start)
+        }
+
+        // Case 1 - fully outside.
+        if input_range.end <= map_range.start || map_range.end <= input_range.start {
+            return ApplyRangeResult {
+                mapped: None,
+                unmapped: vec![input_range],
+            };
+        }
+
+        // Case 2 - fully inside of the map.
+        if input_range.end <= map_range.end && input_range.start >= map_range.start {
+            return ApplyRangeResult {
+                mapped: Some(shift(self, input_range)),
+                unmapped: vec![],
+            };
+        }
+
+        // Case 3 - outside on the left, inside on the right.
+        if input_range.end <= map_range.end && input_range.start < map_range.start {
+            return ApplyRangeResult {
+                mapped: Some(shift(self, map_range.start..input_range.end)),
+                unmapped: vec![input_range.start..map_range.start],
+            };
+        }
+
+        // Case 4 - inside on the left, outside on the right.
+        if input_range.start >= map_range.start && input_range.end > map_range.end {
+            return ApplyRangeResult {
+                mapped: Some(shift(self, input_range.start..map_range.end)),
+                unmapped: vec![map_range.end..input_range.end],
+            };
+        }
+
+        // Case 5 - fully overlaps the map.
+        if input_range.start < map_range.start && input_range.end > map_range.end {
+            return ApplyRangeResult {
+                mapped: Some(shift(self, map_range.start..map_range.end)),
+                unmapped: vec![
+                    input_range.start..map_range.start,
+                    map_range.end..input_range.end,
+                ],
+            };
+        }
+
+        unreachable!("Should have covered every case above");
+    }
+}
+
+impl SoilMap {
+    pub fn apply(&self, v: usize) -> usize {
+        // If any of the inner maps match it, return, otherwise default to the current value.
+        self.0.iter().find_map(|s| s.apply(v)).unwrap_or(v)
+    }
+
+    pub fn apply_range(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        // Fun! Apply the map to a range. This might split a contiguous range up into multiple pieces.
+
+        struct State {
+            mapped: Vec<Range<usize>>,
+            unmapped: Vec<Range<usize>>,
+        }
+
+        let state = State {
+            mapped: vec![],
+            unmapped: vec![range],
+        };
+
+        let state = self.0.iter().fold(state, |state, s| {
+            // For each of our unmapped states, see if we can pass it through the map.
+            let mut new_mapped = vec![];
+            let mut new_unmapped = vec![];
+            let old_mapped = state.mapped;
+            let old_unmapped = state.unmapped;
+
+            for range in old_unmapped.into_iter() {
+                let ApplyRangeResult { mapped, unmapped } = s.apply_range(range);
+                new_mapped.extend(mapped);
+                new_unmapped.extend(unmapped);
+            }
+
+            let mut mapped = old_mapped;
+            mapped.extend(new_mapped);
+
+            State {
+                mapped,
+                unmapped: new_unmapped,
+            }
+        });
+
+        let mut result = vec![];
+        result.extend(state.mapped);
+        // Anything unmapped after the loop just gets mapped to itself.
+        result.extend(state.unmapped);
+        result
+    }
+}
+
+impl SoilMap {
+    // Everything the map's ranges map from.
+    pub fn domain(&self) -> IntervalSet {
+        self.0
+            .iter()
+            .map(|s| s.input_start..s.input_start + s.length)
+            .collect()
+    }
+
+    // Everything the map's ranges map to.
+    pub fn image(&self) -> IntervalSet {
+        self.0
+            .iter()
+            .map(|s| s.output_start..s.output_start + s.length)
+            .collect()
+    }
+
+    // Checks each number the ranges cover gets mapped to a different place, so the map can be run
+    // backwards. The ranges can't overlap at either end: if they overlapped where they map from,
+    // the first one would win and the others would be partly hidden.
+    pub fn check_bijection(&self) -> Result<(), &'static str> {
+        let total: usize = self.0.iter().map(|s| s.length).sum();
+        if self.domain().len() != total {
+            return Err("two of the ranges map from the same place");
+        }
+        if self.image().len() != total {
+            return Err("two of the ranges map to the same place");
+        }
+        Ok(())
+    }
+
+    // The map going the other way, from [image] back to [domain].
+    pub fn invert(&self) -> Result<SoilMap, &'static str> {
+        self.check_bijection()?;
+        Ok(SoilMap(
+            self.0
+                .iter()
+                .map(|s| SoilMapOne {
+                    input_start: s.output_start,
+                    output_start: s.input_start,
+                    length: s.length,
+                })
+                .collect(),
+        ))
+    }
+
+    pub fn apply_set(&self, set: &IntervalSet) -> IntervalSet {
+        set.ranges()
+            .iter()
+            .flat_map(|range| self.apply_range(range.clone()))
+            .collect()
+    }
+
+    // Everything that ends up in [set]. The ranges have to be a bijection, and then that's the
+    // bits of [set] they map to, run backwards, along with the bits that nothing maps away from.
+    pub fn preimage(&self, set: &IntervalSet) -> Result<IntervalSet, &'static str> {
+        let mapped = self.invert()?.apply_set(&set.intersection(&self.image()));
+        Ok(mapped.union(&set.difference(&self.domain())))
+    }
+}
+
+// A whole map on 0.., as sorted pieces that each shift their part of the line by the same amount.
+// Gaps are filled in with pieces that don't move anything, and neighbouring pieces that shift by
+// the same amount are merged, so two maps that do the same thing are always equal. That means a
+// chain of maps can be squashed down into one, and anything can be looked up in it with a binary
+// search rather than going through every [SoilMapOne] of every layer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    // (start, shift): everything from [start] up to the start of the next piece has [shift] added
+    // to it. The first piece starts at 0, and the last one goes on forever.
+    pieces: Vec<(usize, i64)>,
+}
+
+fn shift(v: usize, shift: i64) -> usize {
+    (v as i64 + shift) as usize
+}
+
+impl PiecewiseMap {
+    pub fn identity() -> Self {
+        PiecewiseMap {
+            pieces: vec![(0, 0)],
+        }
+    }
+
+    // Tidies up [pieces], which have to be sorted and start at 0.
+    fn new(mut pieces: Vec<(usize, i64)>) -> Self {
+        pieces.dedup_by_key(|(_start, shift)| *shift);
+        PiecewiseMap { pieces }
+    }
+
+    pub fn pieces(&self) -> &[(usize, i64)] {
+        &self.pieces
+    }
+
+    // The index of the piece [v] is in.
+    fn piece(&self, v: usize) -> usize {
+        self.pieces.partition_point(|(start, _shift)| *start <= v) - 1
+    }
+
+    // Where the [i]th piece stops, if it does.
+    fn end(&self, i: usize) -> Option<usize> {
+        self.pieces.get(i + 1).map(|(start, _shift)| *start)
+    }
+
+    pub fn apply(&self, v: usize) -> usize {
+        shift(v, self.pieces[self.piece(v)].1)
+    }
+
+    // The ranges that [range] gets split into, one for each piece it covers, in the order they
+    // come in [range].
+    pub fn apply_range(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let mut result = vec![];
+        let mut start = range.start;
+        let mut i = self.piece(start);
+        while start < range.end {
+            let end = self.end(i).map_or(range.end, |end| end.min(range.end));
+            let by = self.pieces[i].1;
+            result.push(shift(start, by)..shift(end, by));
+            start = end;
+            i += 1;
+        }
+        result
+    }
+
+    // This map, followed by [next].
+    pub fn then(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let mut pieces = vec![];
+        for (i, (start, by)) in self.pieces.iter().enumerate() {
+            // Follow where this piece ends up through [next], cutting it wherever [next] changes
+            // how much it shifts by.
+            let end = self.end(i);
+            let mut from = *start;
+            let mut j = next.piece(shift(from, *by));
+            loop {
+                pieces.push((from, by + next.pieces[j].1));
+                let Some(cut) = next.end(j).map(|cut| shift(cut, -by)) else {
+                    break;
+                };
+                if end.is_some_and(|end| cut >= end) {
+                    break;
+                }
+                from = cut;
+                j += 1;
+            }
+        }
+        PiecewiseMap::new(pieces)
+    }
+
+    // The map that undoes this one. There's only one if everything gets mapped to from exactly one
+    // place - the pieces have to fit back together without any gaps or overlaps.
+    pub fn invert(&self) -> Option<PiecewiseMap> {
+        let mut images: Vec<(usize, Option<usize>, i64)> = (0..self.pieces.len())
+            .map(|i| {
+                let (start, by) = self.pieces[i];
+                (shift(start, by), self.end(i).map(|end| shift(end, by)), -by)
+            })
+            .collect();
+        images.sort();
+
+        let fits = images[0].0 == 0
+            && images.last().unwrap().1.is_none()
+            && images.windows(2).all(|w| w[0].1 == Some(w[1].0));
+        fits.then(|| {
+            PiecewiseMap::new(
+                images
+                    .into_iter()
+                    .map(|(start, _end, by)| (start, by))
+                    .collect(),
+            )
+        })
+    }
+
+    // Out of everything in [ranges], the one that gets mapped the lowest, as (from, to). On each
+    // piece the lowest is at the start, so we only need to look at those.
+    pub fn lowest(&self, ranges: &[Range<usize>]) -> Option<(usize, usize)> {
+        ranges
+            .iter()
+            .flat_map(|range| {
+                let mut from = range.start;
+                self.apply_range(range.clone()).into_iter().map(move |to| {
+                    let lowest = (from, to.start);
+                    from += to.len();
+                    lowest
+                })
+            })
+            .min_by_key(|(_from, to)| *to)
+    }
+}
+
+impl From<&SoilMap> for PiecewiseMap {
+    fn from(soil_map: &SoilMap) -> Self {
+        // Things only change at the ends of the ranges, and if two ranges overlap, the first one
+        // wins - same as [SoilMap::apply].
+        let mut cuts: Vec<usize> = soil_map
+            .0
+            .iter()
+            .flat_map(|s| [s.input_start, s.input_start + s.length])
+            .chain([0])
+            .collect();
+        cuts.sort();
+        cuts.dedup();
+        let pieces = cuts
+            .into_iter()
+            .map(|cut| {
+                let by = soil_map
+                    .0
+                    .iter()
+                    .find(|s| s.apply(cut).is_some())
+                    .map_or(0, |s| s.output_start as i64 - s.input_start as i64);
+                (cut, by)
+            })
+            .collect();
+        PiecewiseMap::new(pieces)
+    }
+}
+
+impl Problem {
+    // The whole chain of maps, from seed all the way to location, as one map.
+    pub fn seed_to_location(&self) -> PiecewiseMap {
+        self.soil_maps
+            .iter()
+            .fold(PiecewiseMap::identity(), |chain, m| {
+                chain.then(&PiecewiseMap::from(m))
+            })
+    }
+
+    // Which seeds end up in [locations], going back through the maps one at a time.
+    pub fn seeds_for_locations(
+        &self,
+        locations: &IntervalSet,
+    ) -> Result<IntervalSet, &'static str> {
+        self.soil_maps
+            .iter()
+            .rev()
+            .try_fold(locations.clone(), |set, m| m.preimage(&set))
+    }
+
+    // For part 2, where the seeds come in pairs of (start, length). Part 1 is happy with any number
+    // of seeds, so parsing doesn't check there's an even number.
+    pub fn seed_ranges(&self) -> Result<Vec<Range<usize>>, &'static str> {
+        if self.input_seeds.len() % 2 == 1 {
+            return Err("the seeds don't come in pairs");
+        }
+        Ok(self
+            .input_seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect())
+    }
+}
+
+fn parse(s: &str) -> Result<Problem, ParseError> {
+    parse_problem(s.trim()).map_err(|e| e.locate(s))
+}
+
+fn parse_problem(s: &str) -> Result<Problem, ParseError> {
+    // I'm going to be lazy and assume the maps are in the right order in the file, so not read the X-to-Y map lines.
+    // Maybe I'll need those for Part 2 and it'll come back to bite me!
+
+    fn parse_numbers(line: &str) -> Result<Vec<usize>, ParseError> {
+        line.split_whitespace().map(parse::number).collect()
+    }
+
+    let (first_line, rest) = parse::split_once(s, "\n")?;
+    let input_seeds = parse_numbers(parse::strip_prefix(first_line, "seeds:")?)?;
+    if input_seeds.is_empty() {
+        return Err(ParseError::new(first_line, "at least one seed"));
+    }
+
+    let mut soil_maps = vec![];
+
+    for chunk in rest.trim().split("\n\n") {
+        let soil_maps_from_chunk = chunk
+            .lines()
+            .filter(|line| !line.ends_with("map:"))
+            .map(|line| {
+                let v = parse_numbers(line)?;
+                if v.len() != 3 {
+                    return Err(ParseError::new(line, "exactly 3 numbers"));
+                }
+
+                Ok(SoilMapOne {
+                    input_start: v[1],
+                    output_start: v[0],
+                    length: v[2],
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        soil_maps.push(SoilMap(soil_maps_from_chunk));
+    }
+
+    Ok(Problem {
+        input_seeds,
+        soil_maps,
+    })
+}
+
+pub fn solve1(p: &Problem) -> usize {
+    fn location_for_seed(p: &Problem, s: &usize) -> usize {
+        let mut x = *s;
+        for m in &p.soil_maps {
+            x = m.apply(x);
+        }
+        x
+    }
+
+    p.input_seeds
+        .iter()
+        .map(|s| location_for_seed(p, s))
+        .min()
+        .expect("Validated there was at least one seed")
+}
+
+pub fn solve2(p: &Problem) -> Result<usize, &'static str> {
+    let ranges = p.seed_ranges()?;
+
+    let x: Vec<Range<usize>> = p.soil_maps.iter().fold(ranges, |ranges, m| {
+        let ranges = ranges
+            .into_iter()
+            .flat_map(|r| m.apply_range(r).into_iter())
+            .collect();
+        trace!(?ranges, "ranges after the next stage");
+        ranges
+    });
+
+    x.iter()
+        .map(|x| x.start)
+        .min()
+        .ok_or("all the seed ranges are empty")
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Problem;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(problem: &Self::Input) -> Answer {
+        solve1(problem).into()
+    }
+
+    fn part2(problem: &Self::Input) -> Answer {
+        let ranges = match problem.seed_ranges() {
+            Ok(ranges) => ranges,
+            Err(e) => return Answer::Failed(e.to_string()),
+        };
+        match problem.seed_to_location().lowest(&ranges) {
+            Some((_seed, location)) => location.into(),
+            None => Answer::Failed("all the seed ranges are empty".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn parses_example() {
+        let problem = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(problem.input_seeds, vec![79, 14, 55, 13]);
+        assert_eq!(problem.soil_maps.len(), 7);
+        assert_eq!(problem.soil_maps[0].apply(79), 81);
+    }
+
+    #[test]
+    fn splits_ranges_across_maps() {
+        let problem = Day5::parse(EXAMPLE).unwrap();
+        let mut ranges = problem.soil_maps[0].apply_range(45..100);
+        ranges.sort_by_key(|r| r.start);
+        // 45..50 is unmapped, 50..98 moves up by 2, and 98..100 moves down to 50.
+        assert_eq!(ranges, vec![45..50, 50..52, 52..100]);
+    }
+
+    #[test]
+    // A vector of one range, not a vector of numbers.
+    #[allow(clippy::single_range_in_vec_init)]
+    fn composes_the_maps() {
+        let problem = Day5::parse(EXAMPLE).unwrap();
+        let chain = problem.seed_to_location();
+        let one_at_a_time = |seed| problem.soil_maps.iter().fold(seed, |x, m| m.apply(x));
+        for seed in 0..200 {
+            assert_eq!(chain.apply(seed), one_at_a_time(seed), "seed {seed}");
+        }
+
+        // The pieces are sorted and tidied up, and anything past the last map stays put.
+        let pieces = chain.pieces();
+        assert_eq!(pieces[0].0, 0);
+        assert!(pieces
+            .windows(2)
+            .all(|w| w[0].0 < w[1].0 && w[0].1 != w[1].1));
+        assert_eq!(*pieces.last().unwrap(), (100, 0));
+
+        // A range gets split up the same way as going through each map in turn.
+        let mut ranges = chain.apply_range(40..120);
+        let mut expected = problem.soil_maps.iter().fold(vec![40..120], |ranges, m| {
+            ranges.into_iter().flat_map(|r| m.apply_range(r)).collect()
+        });
+        let covered = |ranges: &mut Vec<Range<usize>>| {
+            ranges.sort_by_key(|r| r.start);
+            ranges.iter().flat_map(|r| r.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(covered(&mut ranges), covered(&mut expected));
+    }
+
+    #[test]
+    fn inverts_the_maps() {
+        let problem = Day5::parse(EXAMPLE).unwrap();
+        let chain = problem.seed_to_location();
+        let inverse = chain.invert().unwrap();
+        for seed in 0..200 {
+            assert_eq!(inverse.apply(chain.apply(seed)), seed);
+        }
+        assert_eq!(inverse.then(&chain), PiecewiseMap::identity());
+        assert_eq!(chain.then(&inverse), PiecewiseMap::identity());
+
+        // Two things going to the same place can't be undone.
+        let squash = SoilMap(vec![SoilMapOne {
+            input_start: 10,
+            output_start: 0,
+            length: 5,
+        }]);
+        assert_eq!(PiecewiseMap::from(&squash).invert(), None);
+    }
+
+    #[test]
+    fn runs_maps_backwards() {
+        let problem = Day5::parse(EXAMPLE).unwrap();
+        for m in &problem.soil_maps {
+            assert_eq!(m.check_bijection(), Ok(()));
+            let inverse = m.invert().unwrap();
+            assert_eq!(inverse.domain(), m.image());
+            for v in m.domain().ranges().iter().flat_map(|r| r.clone()) {
+                assert_eq!(inverse.apply(m.apply(v)), v);
+            }
+        }
+
+        // Checked against going forwards from every seed.
+        let chain = problem.seed_to_location();
+        let locations = IntervalSet::from_iter([40..60, 90..95]);
+        let seeds = problem.seeds_for_locations(&locations).unwrap();
+        for seed in 0..200 {
+            assert_eq!(seeds.contains(seed), locations.contains(chain.apply(seed)));
+        }
+        assert_eq!(seeds.len(), locations.len());
+
+        // The lowest location from part 2, and the only seed in the seed ranges that goes there.
+        let seeds = problem.seeds_for_locations(&(46..47).into()).unwrap();
+        let seed_ranges = IntervalSet::from_iter(problem.seed_ranges().unwrap());
+        assert_eq!(seeds.intersection(&seed_ranges), (82..83).into());
+    }
+
+    #[test]
+    fn only_bijections_can_be_inverted() {
+        let map = |ranges: &[(usize, usize, usize)]| {
+            SoilMap(
+                ranges
+                    .iter()
+                    .map(|(output_start, input_start, length)| SoilMapOne {
+                        input_start: *input_start,
+                        output_start: *output_start,
+                        length: *length,
+                    })
+                    .collect(),
+            )
+        };
+        assert_eq!(
+            map(&[(0, 10, 5), (20, 12, 5)]).invert().unwrap_err(),
+            "two of the ranges map from the same place"
+        );
+        assert_eq!(
+            map(&[(0, 10, 5), (3, 20, 5)]).invert().unwrap_err(),
+            "two of the ranges map to the same place"
+        );
+
+        // Something that maps 10..15 down to 0..5 is fine, even though 0..5 also stays put. Then
+        // 0..5 comes from both.
+        let squash = map(&[(0, 10, 5)]);
+        assert_eq!(
+            squash.preimage(&(0..5).into()),
+            Ok(IntervalSet::from_iter([0..5, 10..15]))
+        );
+        assert_eq!(squash.preimage(&(10..15).into()), Ok(IntervalSet::new()));
+    }
+
+    #[test]
+    fn finds_the_lowest_location() {
+        let problem = Day5::parse(EXAMPLE).unwrap();
+        let chain = problem.seed_to_location();
+        // From the puzzle: seed 82 goes to location 46.
+        assert_eq!(
+            chain.lowest(&problem.seed_ranges().unwrap()),
+            Some((82, 46))
+        );
+        assert_eq!(chain.lowest(&[]), None);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input), Answer::Integer(35));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input), Answer::Integer(46));
+    }
+
+    #[test]
+    fn part2_needs_pairs_of_seeds() {
+        let input = Day5::parse(&EXAMPLE.replace("55 13", "55")).unwrap();
+        assert_eq!(Day5::part1(&input), Answer::Integer(43));
+        assert_eq!(
+            Day5::part2(&input),
+            Answer::Failed("the seeds don't come in pairs".to_string())
+        );
+        assert_eq!(solve2(&input), Err("the seeds don't come in pairs"));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day05.txt and answers/day05.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day5>(5);
+    }
+}
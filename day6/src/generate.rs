@@ -0,0 +1,48 @@
+use crate::Day6;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+impl Generate for Day6 {
+    const SIZE: &'static str = "races";
+    const DEFAULT_SIZE: usize = 4;
+    // Part 2 sticks the numbers together, and the records can be 4 digits long, so any more races
+    // than this might not fit in a u64.
+    const MAX_SIZE: usize = 4;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        let mut times = format!("{:<9}", "Time:");
+        let mut distances = String::from("Distance:");
+        for _ in 0..size {
+            // The record is what you'd get from holding the button for some time, short enough that
+            // there's always a way to beat it.
+            let time: u64 = rng.gen_range(10..=99);
+            let hold = rng.gen_range(1..=(time - 2) / 2);
+            let distance = hold * (time - hold);
+
+            // Line the numbers up in columns, like the real inputs.
+            let width = distance.to_string().len().max(time.to_string().len()) + 2;
+            times.push_str(&format!("{:>width$}", time));
+            distances.push_str(&format!("{:>width$}", distance));
+        }
+        format!("{}\n{}\n", times, distances)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solution::{Answer, Solution};
+    use rand::SeedableRng;
+
+    #[test]
+    fn generated_races_can_always_be_won() {
+        for seed in 0..10 {
+            let s = Day6::generate(&mut StdRng::seed_from_u64(seed), 2, 0.5);
+            let input = Day6::parse(&s).unwrap();
+            assert_eq!(input.separate.len(), 2);
+            assert_ne!(Day6::part1(&input), Answer::Integer(0));
+            assert!(input.kerned.is_some());
+        }
+    }
+}
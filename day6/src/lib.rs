@@ -0,0 +1,209 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use num_bigint::BigInt;
+
+mod generate;
+
+// How many ways there are to hold the button for a race lasting [time] so that the boat goes
+// further than [record].
+pub fn number_of_ways_beating_bound(time: u64, record: u64) -> u64 {
+    // Holding for h goes h * (time - h), so we want h^2 - time * h + record < 0, which is true
+    // strictly between the roots of the quadratic, (time +- sqrt(time^2 - 4 * record)) / 2. Doing
+    // it in u128 means time^2 can't overflow, and [isqrt] is exact where a float square root
+    // wouldn't be once the numbers get big.
+    let (time, record) = (time as u128, record as u128);
+    let beats = |h: u128| h * (time - h) > record;
+    let middle = time / 2;
+    if !beats(middle) {
+        // The best you can do is to hold for half the time, and even that isn't enough.
+        return 0;
+    }
+
+    // The square root is rounded down, so the first hold time that wins is near the lower root but
+    // might be one or two either side of where we guess.
+    let discriminant = time * time - 4 * record;
+    let mut first = ((time - discriminant.isqrt()) / 2).min(middle);
+    while !beats(first) {
+        first += 1;
+    }
+    while first > 0 && beats(first - 1) {
+        first -= 1;
+    }
+
+    // It's symmetric: holding for h goes as far as holding for time - h.
+    (time - 2 * first + 1) as u64
+}
+
+// How to read the sheet of paper. It's either separate races, or - as it turns out in part 2 - one
+// race with bad kerning, where the spaces between the numbers should be ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kerning {
+    Separate,
+    Kerned,
+}
+
+// (time, record distance) for each race.
+pub fn parse_races(s: &str, kerning: Kerning) -> Result<Vec<(u64, u64)>, ParseError> {
+    let parse_row = |line: &str, prefix: &str| -> Result<Vec<u64>, ParseError> {
+        let numbers = parse::strip_prefix(line.trim(), prefix)?.trim();
+        match kerning {
+            Kerning::Separate => numbers.split_whitespace().map(parse::number).collect(),
+            Kerning::Kerned => {
+                let digits: String = numbers.split_whitespace().collect();
+                let number = digits.parse().map_err(|_| {
+                    ParseError::new(
+                        numbers,
+                        "numbers that fit in 64 bits with the spaces taken out",
+                    )
+                })?;
+                Ok(vec![number])
+            }
+        }
+    };
+
+    let s = s.trim();
+    let (times_line, distances_line) = parse::split_once(s, "\n")?;
+    let times = parse_row(times_line, "Time:")?;
+    let distances = parse_row(distances_line, "Distance:")?;
+
+    if times.len() != distances.len() {
+        return Err(ParseError::new(
+            distances_line.trim(),
+            format!("{} distances, one for each race", times.len()),
+        ));
+    }
+
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+fn ways_to_win(races: &[(u64, u64)]) -> BigInt {
+    races
+        .iter()
+        .map(|(time, record)| BigInt::from(number_of_ways_beating_bound(*time, *record)))
+        .product()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Races {
+    pub separate: Vec<(u64, u64)>,
+    // The one kerned race, if its numbers fit.
+    pub kerned: Option<(u64, u64)>,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Races;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let separate = parse_races(s, Kerning::Separate).map_err(|e| e.locate(s))?;
+        if separate.is_empty() {
+            return Err(ParseError::new(parse::end_of(s), "at least one race").locate(s));
+        }
+        // If the separate races parsed, the only way this can fail is the numbers getting too big
+        // once they're stuck together - which only matters for part 2.
+        let kerned = parse_races(s, Kerning::Kerned).ok().map(|races| races[0]);
+        Ok(Races { separate, kerned })
+    }
+
+    fn part1(races: &Self::Input) -> Answer {
+        ways_to_win(&races.separate).into()
+    }
+
+    fn part2(races: &Self::Input) -> Answer {
+        match races.kerned {
+            Some(race) => ways_to_win(&[race]).into(),
+            None => Answer::Failed("the kerned numbers don't fit in 64 bits".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn parses_example() {
+        let races = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(races.separate, vec![(7, 9), (15, 40), (30, 200)]);
+        assert_eq!(races.kerned, Some((71530, 940200)));
+        assert_eq!(
+            parse_races(EXAMPLE, Kerning::Kerned),
+            Ok(vec![(71530, 940200)])
+        );
+    }
+
+    #[test]
+    fn kerned_numbers_can_be_too_big() {
+        let s = "Time: 1234567890 1234567890 12\nDistance: 1 2 3\n";
+        let races = Day6::parse(s).unwrap();
+        assert_eq!(races.kerned, None);
+        assert_eq!(
+            Day6::part2(&races),
+            Answer::Failed("the kerned numbers don't fit in 64 bits".to_string())
+        );
+        let e = parse_races(s, Kerning::Kerned).unwrap_err();
+        assert_eq!(e.token(), "1234567890 1234567890 12");
+    }
+
+    #[test]
+    fn counts_ways_exactly() {
+        // Against trying every hold time.
+        for time in 0..60 {
+            for record in 0..=time * time / 4 + 1 {
+                let naive = (0..=time).filter(|h| h * (time - h) > record).count() as u64;
+                assert_eq!(
+                    number_of_ways_beating_bound(time, record),
+                    naive,
+                    "time {time}, record {record}"
+                );
+            }
+        }
+
+        // Big enough that a float square root would be off. Holding for h or time - h goes exactly
+        // h * (time - h), which doesn't beat itself, so the winners are everything in between.
+        for (time, h) in [
+            (u64::MAX, 1),
+            (1 << 40, 1 << 20),
+            (1_000_000_000_000, 1_000_000),
+            (6_000_000_000, 1_999_999_999),
+        ] {
+            let record = h * (time - h);
+            assert_eq!(number_of_ways_beating_bound(time, record), time - 2 * h - 1);
+            assert_eq!(
+                number_of_ways_beating_bound(time, record - 1),
+                time - 2 * h + 1
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_mismatched_rows() {
+        let e = Day6::parse("Time: 7 15\nDistance: 9").unwrap_err();
+        assert_eq!(e.expected(), "2 distances, one for each race");
+        assert_eq!(e.location().unwrap().line, 2);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&input), Answer::Integer(288));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&input), Answer::Integer(71503));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day06.txt and answers/day06.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day6>(6);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::Day7;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+const CARDS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+impl Generate for Day7 {
+    const SIZE: &'static str = "hands";
+    const DEFAULT_SIZE: usize = 1000;
+    // Every hand is different.
+    const MAX_SIZE: usize = 13usize.pow(5);
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        assert!(
+            size <= 13usize.pow(5),
+            "There are only 13^5 different hands"
+        );
+
+        // Every hand is different, so there are never any ties.
+        let mut seen = HashSet::new();
+        let mut result = String::new();
+        while seen.len() < size {
+            // Picking from a few cards makes the better categories more likely, like in the real
+            // inputs. Otherwise almost every hand would be high card or one pair.
+            let different_cards = rng.gen_range(1..=5);
+            let pool: Vec<char> = CARDS
+                .choose_multiple(rng, different_cards)
+                .copied()
+                .collect();
+            let hand: String = (0..5).map(|_| *pool.choose(rng).unwrap()).collect();
+            if seen.insert(hand.clone()) {
+                result.push_str(&format!("{} {}\n", hand, rng.gen_range(1..=1000)));
+            }
+        }
+        result
+    }
+}
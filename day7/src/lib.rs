@@ -0,0 +1,244 @@
+use aoc_common::parse::{self, char_token, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Category {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl Category {
+    fn to_usize(&self) -> usize {
+        // Higher means better.
+        match self {
+            Category::FiveOfAKind => 6,
+            Category::FourOfAKind => 5,
+            Category::FullHouse => 4,
+            Category::ThreeOfAKind => 3,
+            Category::TwoPair => 2,
+            Category::OnePair => 1,
+            Category::HighCard => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    pub category: Category,
+    pub raw_hand: [usize; 5],
+    pub bid: usize,
+}
+
+impl Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // First of all, compare the category.
+        match self.category.to_usize().cmp(&other.category.to_usize()) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Equal => {
+                // Of the same category - fall back to comparing the raw hands.
+                self.raw_hand.cmp(&other.raw_hand)
+            }
+        }
+    }
+
+    fn parse(line: &str, apply_joker_rule: bool) -> Result<Self, ParseError> {
+        // This supports both with and without jokers. The joker only comes in in one place - whether or not
+        // we interpret jokers as "1" or "11". The rest of the code then works in the same way.
+        let (cards, bid) = parse::split_once(line, " ")?;
+        let bid: usize = parse::number(bid.trim())?;
+
+        let raw_hand: [usize; 5] = cards
+            .char_indices()
+            .map(|(i, x)| match x {
+                '2'..='9' => Ok(x.to_digit(10).unwrap() as usize),
+                'T' => Ok(10),
+                'J' => {
+                    if apply_joker_rule {
+                        Ok(1)
+                    } else {
+                        Ok(11)
+                    }
+                }
+                'Q' => Ok(12),
+                'K' => Ok(13),
+                'A' => Ok(14),
+                _ => Err(ParseError::new(
+                    char_token(cards, i),
+                    "a card (2-9, T, J, Q, K or A)",
+                )),
+            })
+            .collect::<Result<Vec<usize>, _>>()?
+            .try_into()
+            .map_err(|_| ParseError::new(cards, "a hand of 5 cards"))?;
+
+        let mut numbers_to_count = HashMap::new();
+        for x in raw_hand {
+            let y = numbers_to_count.entry(x).or_insert(0usize);
+            *y += 1
+        }
+
+        // Return the number of "normal" numbers - i.e. not including jokers - that appear n times.
+        let get_numbers_appearing_n_times = |n: usize| -> Vec<usize> {
+            numbers_to_count
+                .iter()
+                .filter(|(x, y)| **x != 1 && **y == n)
+                .map(|(x, _y)| x)
+                .copied()
+                .collect()
+        };
+
+        let five = get_numbers_appearing_n_times(5);
+        let four = get_numbers_appearing_n_times(4);
+        let three = get_numbers_appearing_n_times(3);
+        let two = get_numbers_appearing_n_times(2);
+        let one = get_numbers_appearing_n_times(1);
+        let number_of_jokers = *numbers_to_count.get(&1).unwrap_or(&0);
+
+        let category = {
+            match (
+                five.len(),
+                four.len(),
+                three.len(),
+                two.len(),
+                one.len(),
+                number_of_jokers,
+            ) {
+                // Possibilities with 5 jokers.
+                (0, 0, 0, 0, 0, 5) => Category::FiveOfAKind,
+                // Possibilities with 4 jokers.
+                (0, 0, 0, 0, 1, 4) => Category::FiveOfAKind,
+                // Possibilities with 3 jokers.
+                (0, 0, 0, 0, 2, 3) => Category::FourOfAKind,
+                (0, 0, 0, 1, 0, 3) => Category::FiveOfAKind,
+                // Possibilities with 2 jokers.
+                (0, 0, 0, 0, 3, 2) => Category::ThreeOfAKind,
+                (0, 0, 0, 1, 1, 2) => Category::FourOfAKind,
+                (0, 0, 1, 0, 0, 2) => Category::FiveOfAKind,
+                // Possibilities with 1 joker.
+                (0, 0, 0, 0, 4, 1) => Category::OnePair,
+                (0, 0, 0, 1, 2, 1) => Category::ThreeOfAKind,
+                (0, 0, 0, 2, 0, 1) => Category::FullHouse,
+                (0, 0, 1, 0, 1, 1) => Category::FourOfAKind,
+                (0, 1, 0, 0, 0, 1) => Category::FiveOfAKind,
+                // Possibilities with 0 jokers.
+                (1, 0, 0, 0, 0, 0) => Category::FiveOfAKind,
+                (0, 1, 0, 0, 1, 0) => Category::FourOfAKind,
+                (0, 0, 1, 1, 0, 0) => Category::FullHouse,
+                (0, 0, 1, 0, 2, 0) => Category::ThreeOfAKind,
+                (0, 0, 0, 2, 1, 0) => Category::TwoPair,
+                (0, 0, 0, 1, 3, 0) => Category::OnePair,
+                (0, 0, 0, 0, 5, 0) => Category::HighCard,
+                _ => panic!("Shouldn't be able to get any other ways of making 5."),
+            }
+        };
+
+        Ok(Hand {
+            raw_hand,
+            category,
+            bid,
+        })
+    }
+}
+
+fn total_winnings(hands: &[Hand]) -> usize {
+    let mut hands = hands.to_vec();
+    hands.sort_by(Hand::cmp);
+
+    hands.iter().enumerate().map(|(i, h)| (i + 1) * h.bid).sum()
+}
+
+// The hands read both ways, since the joker rule changes how they're parsed.
+#[derive(Debug)]
+pub struct Hands {
+    pub without_jokers: Vec<Hand>,
+    pub with_jokers: Vec<Hand>,
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Hands;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let parse_hands = |apply_joker_rule: bool| {
+            s.trim()
+                .lines()
+                .map(|line| Hand::parse(line, apply_joker_rule))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| e.locate(s))
+        };
+
+        Ok(Hands {
+            without_jokers: parse_hands(false)?,
+            with_jokers: parse_hands(true)?,
+        })
+    }
+
+    fn part1(hands: &Self::Input) -> Answer {
+        total_winnings(&hands.without_jokers).into()
+    }
+
+    fn part2(hands: &Self::Input) -> Answer {
+        total_winnings(&hands.with_jokers).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn parses_example() {
+        let hands = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(hands.without_jokers.len(), 5);
+        assert_eq!(hands.without_jokers[0].raw_hand, [3, 2, 10, 3, 13]);
+        assert_eq!(hands.without_jokers[0].bid, 765);
+
+        // KTJJT is two pair normally, but four of a kind with the jokers.
+        assert_eq!(hands.without_jokers[3].category, Category::TwoPair);
+        assert_eq!(hands.with_jokers[3].category, Category::FourOfAKind);
+    }
+
+    #[test]
+    fn rejects_bad_cards() {
+        let e = Day7::parse("32T3X 765").unwrap_err();
+        assert_eq!(e.token(), "X");
+        assert_eq!(e.location().unwrap().column, 5);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input), Answer::Integer(6440));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input), Answer::Integer(5905));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day07.txt and answers/day07.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day7>(7);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::Day8;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::Rng;
+use std::collections::HashSet;
+
+// A random name of 3 capital letters, ending in one of [last].
+fn random_name(rng: &mut StdRng, last: &[char], seen: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = [
+            rng.gen_range('A'..='Z'),
+            rng.gen_range('A'..='Z'),
+            *last.choose(rng).unwrap(),
+        ]
+        .into_iter()
+        .collect();
+        if seen.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+// The input, along with the number of steps from AAA to ZZZ.
+fn network(rng: &mut StdRng, size: usize) -> (String, usize) {
+    assert!(size >= 2, "Need at least AAA and ZZZ");
+    let middle_letters: Vec<char> = ('B'..='Y').collect();
+    assert!(size <= 24 * 26 * 26, "Not enough names to go round");
+
+    // AAA and ZZZ, a few more ghosts and places for them to finish, and everything else.
+    let mut seen: HashSet<String> = ["AAA", "ZZZ"].map(String::from).into_iter().collect();
+    let ghosts = ((size - 2) / 4).min(5);
+    let mut names = vec!["AAA".to_string(), "ZZZ".to_string()];
+    names.extend((0..ghosts).map(|_| random_name(rng, &['A'], &mut seen)));
+    names.extend((0..ghosts).map(|_| random_name(rng, &['Z'], &mut seen)));
+    while names.len() < size {
+        names.push(random_name(rng, &middle_letters, &mut seen));
+    }
+
+    let dirs: Vec<usize> = (0..rng.gen_range(1..=300))
+        .map(|_| rng.gen_range(0..2))
+        .collect();
+
+    // Plant a path from AAA to ZZZ through distinct nodes, so part 1 always finishes. Each node
+    // on the path only needs one of its outputs, for whichever direction we're going at that step.
+    let steps = rng.gen_range(1..size);
+    let mut path = vec![0];
+    path.extend(
+        index::sample(rng, size - 2, steps - 1)
+            .into_iter()
+            .map(|i| i + 2),
+    );
+    path.push(1);
+
+    let mut outputs: Vec<[Option<usize>; 2]> = vec![[None, None]; size];
+    for (step, nodes) in path.windows(2).enumerate() {
+        outputs[nodes[0]][dirs[step % dirs.len()]] = Some(nodes[1]);
+    }
+
+    let mut lines: Vec<String> = outputs
+        .iter()
+        .enumerate()
+        .map(|(i, [left, right])| {
+            let left = left.unwrap_or_else(|| rng.gen_range(0..size));
+            let right = right.unwrap_or_else(|| rng.gen_range(0..size));
+            format!("{} = ({}, {})", names[i], names[left], names[right])
+        })
+        .collect();
+    lines.shuffle(rng);
+
+    let dirs: String = dirs.iter().map(|d| ['L', 'R'][*d]).collect();
+    (format!("{}\n\n{}\n", dirs, lines.join("\n")), steps)
+}
+
+impl Generate for Day8 {
+    const SIZE: &'static str = "nodes";
+    const DEFAULT_SIZE: usize = 750;
+    // AAA and ZZZ, and names to go round.
+    const MIN_SIZE: usize = 2;
+    const MAX_SIZE: usize = 24 * 26 * 26;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        network(rng, size).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solution::{Answer, Solution};
+    use rand::SeedableRng;
+
+    #[test]
+    fn part1_follows_the_planted_path() {
+        for seed in 0..10 {
+            let (s, steps) = network(&mut StdRng::seed_from_u64(seed), 100);
+            let input = Day8::parse(&s).unwrap();
+            assert_eq!(input.nodes.len(), 100);
+            assert_eq!(Day8::part1(&input), Answer::from(steps));
+        }
+    }
+}
@@ -0,0 +1,399 @@
+use aoc_common::maths::crt;
+use aoc_common::parse::{self, char_token, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use std::collections::{BTreeSet, HashMap};
+use tracing::{info, trace};
+
+mod generate;
+
+#[derive(Debug)]
+pub enum Dir {
+    Left,
+    Right,
+}
+#[derive(Debug)]
+pub struct Node {
+    pub left: String,
+    pub right: String,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub nodes: HashMap<String, Node>,
+    pub dirs: Vec<Dir>,
+}
+
+fn read_lines(s: &str) -> Result<Game, ParseError> {
+    read_game(s.trim()).map_err(|e| e.locate(s))
+}
+
+fn read_game(s: &str) -> Result<Game, ParseError> {
+    let (dirs, s) = parse::split_once(s, "\n")?;
+    let dirs = dirs.trim();
+
+    let dirs: Vec<Dir> = dirs
+        .char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(Dir::Left),
+            'R' => Ok(Dir::Right),
+            _ => Err(ParseError::new(char_token(dirs, i), "L or R")),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut nodes = HashMap::new();
+    // Where each node points, so we can check they all exist once we've seen every node.
+    let mut outputs_to_check = vec![];
+
+    for line in s.trim().lines() {
+        let (name, outputs) = parse::split_once(line, " = ")?;
+        let outputs = parse::strip_prefix(outputs, "(")?;
+        let outputs = parse::strip_suffix(outputs, ")")?;
+        let (left, right) = parse::split_once(outputs, ", ")?;
+        outputs_to_check.extend([left, right]);
+
+        let node = Node {
+            left: left.to_string(),
+            right: right.to_string(),
+        };
+        let name = name.to_string();
+        nodes.insert(name, node);
+    }
+
+    if let Some(output) = outputs_to_check.iter().find(|o| !nodes.contains_key(**o)) {
+        return Err(ParseError::new(output, "the name of a node"));
+    }
+
+    Ok(Game { nodes, dirs })
+}
+
+pub fn solve_part1(game: &Game) -> usize {
+    // Part 1 - how many times does it take us to reach ZZZ from AAA?
+    let mut name = "AAA";
+    let mut node = game.nodes.get("AAA").expect("No AAA node in input?");
+    let mut count = 0;
+
+    let finish_name = "ZZZ";
+
+    while name.ne(finish_name) {
+        let dir = &game.dirs[count % game.dirs.len()];
+        name = {
+            match dir {
+                Dir::Left => &node.left,
+                Dir::Right => &node.right,
+            }
+        };
+        node = game.nodes.get(name).unwrap();
+
+        count += 1;
+    }
+    count
+}
+
+// Given any node, it eventually reaches some form of cycle - the path is fully determined by the
+// [Key] type below. So we must hit some point where the thing starts cycling, as there's only
+// (dirs * nodes) number of possible keys. In our real input, that's about 200_000.
+#[derive(Debug)]
+struct CycleStructure {
+    moves_before_cycle: usize,
+    cycle_length: usize,
+    // Every step count up to the end of the first time round the cycle where we're on a Z node, in
+    // order. The ones from [moves_before_cycle] onwards come round again every [cycle_length] steps.
+    good_nodes_on_path: Vec<usize>,
+}
+
+impl CycleStructure {
+    fn is_good(&self, count: usize) -> bool {
+        let count = if count < self.moves_before_cycle {
+            count
+        } else {
+            self.moves_before_cycle + (count - self.moves_before_cycle) % self.cycle_length
+        };
+        self.good_nodes_on_path.binary_search(&count).is_ok()
+    }
+
+    // The remainders mod [cycle_length] of the step counts where we're on a Z node, once we're in
+    // the cycle.
+    fn good_remainders(&self) -> Vec<BigInt> {
+        self.good_nodes_on_path
+            .iter()
+            .filter(|count| **count >= self.moves_before_cycle)
+            .map(|count| BigInt::from(count % self.cycle_length))
+            .collect()
+    }
+}
+
+#[derive(Eq, PartialEq, Hash)]
+struct Key {
+    count_modulo_dir_length: usize,
+    node: String,
+}
+
+fn determine_cycle_structure(game: &Game, name: &str) -> CycleStructure {
+    let mut key_to_first_visit: HashMap<Key, usize> = HashMap::new();
+    let mut count = 0;
+    let mut name = name;
+    let mut good_nodes_on_path = vec![];
+
+    loop {
+        let count_modulo_dir_length = count % game.dirs.len();
+        // Check if we're at a loop.
+        let key = Key {
+            count_modulo_dir_length,
+            node: name.to_string(),
+        };
+
+        match key_to_first_visit.get(&key) {
+            Some(spot) => {
+                // We've previously visited this spot, so we have our cycle!
+                let moves_before_cycle = *spot;
+                let cycle_length = count - moves_before_cycle;
+                return CycleStructure {
+                    moves_before_cycle,
+                    cycle_length,
+                    good_nodes_on_path,
+                };
+            }
+            None => {
+                // First time we're visiting this key.
+                key_to_first_visit.insert(key, count);
+                if name.ends_with("Z") {
+                    good_nodes_on_path.push(count);
+                }
+            }
+        }
+
+        let dir = &game.dirs[count % game.dirs.len()];
+        let node = game.nodes.get(name).unwrap();
+
+        name = {
+            match dir {
+                Dir::Left => &node.left,
+                Dir::Right => &node.right,
+            }
+        };
+        count += 1;
+    }
+}
+
+// The first step count where every ghost is on a Z node at once, or [None] if that never happens.
+fn first_synchronised_step(structures: &[CycleStructure]) -> Option<BigInt> {
+    // Before every ghost has got into its cycle, just check each step. These are the only steps where
+    // the Z nodes before the cycles count, so after this we can forget about them.
+    let all_cycling = structures
+        .iter()
+        .map(|s| s.moves_before_cycle)
+        .max()
+        .unwrap_or(0);
+    if let Some(count) = (0..all_cycling).find(|count| structures.iter().all(|s| s.is_good(*count)))
+    {
+        return Some(count.into());
+    }
+
+    // After that, each ghost is on a Z node when the step count is one of a few remainders mod its
+    // cycle length. Combine these one ghost at a time, to get every remainder mod the LCM of the cycle
+    // lengths that works for all the ghosts so far. In the real input there's exactly one remainder
+    // for each ghost, so this stays small - with more, it could grow as the product of the number
+    // of remainders, so start with the ghosts with the fewest to rule out as much as possible early.
+    let mut structures: Vec<&CycleStructure> = structures.iter().collect();
+    structures.sort_by_key(|s| s.good_remainders().len());
+
+    let mut solutions: Vec<(BigInt, BigInt)> = vec![(BigInt::zero(), BigInt::one())];
+    for structure in structures {
+        let cycle_length = BigInt::from(structure.cycle_length);
+        let remainders = structure.good_remainders();
+        let mut next_solutions = BTreeSet::new();
+        for (a, m) in &solutions {
+            for b in &remainders {
+                next_solutions.extend(crt(a, m, b, &cycle_length));
+            }
+        }
+        trace!("{} remainders work so far", next_solutions.len());
+        solutions = next_solutions.into_iter().collect();
+    }
+
+    // The first step at or after [all_cycling] with each remainder.
+    let all_cycling = BigInt::from(all_cycling);
+    solutions
+        .into_iter()
+        .map(|(a, m)| {
+            if a >= all_cycling {
+                a
+            } else {
+                &a + (&all_cycling - &a).div_ceil(&m) * m
+            }
+        })
+        .min()
+}
+
+pub fn solve_part2(game: &Game) -> Option<BigInt> {
+    // Part 2 is weird!
+    // We want to start on ALL of the nodes that start with 'A', and move simultaeneously until
+    // we're all on nodes that end with 'Z'.
+    //
+    // The question hints this is going to be significantly more steps. So I might need to do some sort
+    // of periodic thing.
+    let mut starting_nodes: Vec<&String> = game.nodes.keys().filter(|s| s.ends_with("A")).collect();
+    starting_nodes.sort();
+
+    let structures: Vec<CycleStructure> = starting_nodes
+        .into_iter()
+        .map(|node| {
+            let structure = determine_cycle_structure(game, node);
+            info!("{}: {:?}", node, structure);
+            structure
+        })
+        .collect();
+
+    // The real input is a very special case. E.g. one of my cycles:
+    // CVA: CycleStructure { moves_before_cycle: 2, cycle_length: 22357, good_nodes_on_path: [22357] }
+    //
+    // They're all of this form, where the answer is "it hits a Z node every N nodes from the start",
+    // so the answer is just the LCM of the cycle lengths - I originally worked it out by hand like
+    // that. In general there can be more than one Z node in a cycle, they don't have to line up with
+    // the start, and there can be Z nodes before the cycle starts, so this handles all of that.
+    first_synchronised_step(&structures)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Game;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        read_lines(s)
+    }
+
+    fn part1(game: &Self::Input) -> Answer {
+        solve_part1(game).into()
+    }
+
+    fn part2(game: &Self::Input) -> Answer {
+        match solve_part2(game) {
+            Some(steps) => steps.into(),
+            None => Answer::Failed("the ghosts never synchronise".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+    const EXAMPLE2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    #[test]
+    fn parses_example() {
+        let game = Day8::parse(EXAMPLE1).unwrap();
+        assert_eq!(game.dirs.len(), 2);
+        assert_eq!(game.nodes.len(), 7);
+        assert_eq!(game.nodes["AAA"].left, "BBB");
+        assert_eq!(game.nodes["AAA"].right, "CCC");
+    }
+
+    #[test]
+    fn rejects_missing_nodes() {
+        let e = Day8::parse("L\n\nAAA = (BBB, AAA)").unwrap_err();
+        assert_eq!(e.token(), "BBB");
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day8::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day8::part1(&input), Answer::Integer(2));
+    }
+
+    #[test]
+    fn part1_example_repeating_directions() {
+        let input = Day8::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day8::part1(&input), Answer::Integer(6));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day8::parse(
+            "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+",
+        )
+        .unwrap();
+        assert_eq!(Day8::part2(&input), Answer::Integer(6));
+    }
+
+    #[test]
+    fn synchronises_in_general() {
+        let structure =
+            |moves_before_cycle, cycle_length, good_nodes_on_path: &[usize]| CycleStructure {
+                moves_before_cycle,
+                cycle_length,
+                good_nodes_on_path: good_nodes_on_path.to_vec(),
+            };
+        let first = |structures: &[CycleStructure]| {
+            first_synchronised_step(structures).map(|x| i64::try_from(x).unwrap())
+        };
+
+        // Two Z nodes in the first cycle, and one before the second cycle starts which doesn't
+        // line up with anything. Step 1 would work if the second cycle had started by then.
+        assert_eq!(
+            first(&[structure(0, 4, &[1, 3]), structure(2, 3, &[0, 4])]),
+            Some(7)
+        );
+        // Both on a Z node before the first cycle starts.
+        assert_eq!(
+            first(&[structure(5, 2, &[3]), structure(0, 3, &[0])]),
+            Some(3)
+        );
+        // One ghost is only on a Z node on even steps, and the other only on odd ones.
+        assert_eq!(first(&[structure(0, 2, &[0]), structure(0, 4, &[1])]), None);
+        // No Z nodes in the cycle at all.
+        assert_eq!(first(&[structure(0, 2, &[]), structure(0, 4, &[1])]), None);
+    }
+
+    #[test]
+    fn needs_big_integers_for_long_cycles() {
+        let structures: Vec<CycleStructure> = [1_000_003, 1_000_033, 1_000_037, 1_000_039]
+            .into_iter()
+            .map(|p| CycleStructure {
+                moves_before_cycle: 1,
+                cycle_length: p,
+                good_nodes_on_path: vec![p],
+            })
+            .collect();
+        let expected = BigInt::from(1_000_003u64 * 1_000_033) * (1_000_037u64 * 1_000_039);
+        assert_eq!(first_synchronised_step(&structures), Some(expected));
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day08.txt and answers/day08.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day8>(8);
+    }
+}
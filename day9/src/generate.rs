@@ -0,0 +1,58 @@
+use crate::Day9;
+use aoc_common::generate::Generate;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+const VALUES: usize = 21;
+
+impl Generate for Day9 {
+    const SIZE: &'static str = "histories";
+    const DEFAULT_SIZE: usize = 200;
+
+    fn generate(rng: &mut StdRng, size: usize, _density: f64) -> String {
+        let mut result = String::new();
+        for _ in 0..size {
+            // Pick the first value of each row of differences, and a constant bottom row, then add
+            // them back up. That's a polynomial with integer values of the chosen degree.
+            let degree = rng.gen_range(0..=8);
+            let mut values = vec![rng.gen_range(-10i64..=10); VALUES];
+            for _ in 0..degree {
+                let mut total = rng.gen_range(-10..=10);
+                values = values
+                    .iter()
+                    .map(|difference| {
+                        let value = total;
+                        total += difference;
+                        value
+                    })
+                    .collect();
+            }
+
+            let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+            result.push_str(&values.join(" "));
+            result.push('\n');
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solution::Solution;
+    use rand::SeedableRng;
+
+    #[test]
+    fn generated_histories_are_low_degree_polynomials() {
+        for seed in 0..10 {
+            let s = Day9::generate(&mut StdRng::seed_from_u64(seed), 50, 0.5);
+            let input = Day9::parse(&s).unwrap();
+            assert!(input.iter().all(|values| values.len() == VALUES));
+            // A row of differences might come out as all zeros, so the degree can be less than
+            // what was picked, but never more.
+            for values in &input {
+                assert!(crate::fit(values).unwrap().degree() <= 8);
+            }
+        }
+    }
+}
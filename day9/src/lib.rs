@@ -0,0 +1,165 @@
+use aoc_common::parse::{self, ParseError};
+use aoc_common::solution::{Answer, Solution};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+
+mod generate;
+
+// The polynomial a sequence of values comes from, as the first value and the first of each row of
+// differences below it - like the puzzle's pyramids, down to the first row that's all the same.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    differences: Vec<BigInt>,
+}
+
+impl Polynomial {
+    pub fn degree(&self) -> usize {
+        self.differences.len() - 1
+    }
+
+    // The value at [x], where the values we were given were at 0, 1, 2, ... [x] can be anywhere,
+    // including before the start.
+    pub fn at(&self, x: i64) -> BigInt {
+        // Newton's forward difference formula: the sum of the kth difference times (x choose k).
+        // (x choose k) is still an integer when x is negative, and the division's always exact,
+        // because it's a product of k consecutive integers divided by k!.
+        let x = BigInt::from(x);
+        let mut total = BigInt::zero();
+        let mut choose = BigInt::one();
+        for (k, difference) in self.differences.iter().enumerate() {
+            total += difference * &choose;
+            choose = choose * (&x - k) / (k + 1);
+        }
+        total
+    }
+}
+
+// Works out the differences between the values, and the differences between those, and so on,
+// until they're all the same. Fails if they never are - then the values could come from any
+// polynomial of high enough degree, so there's no telling what comes next.
+pub fn fit(values: &[i64]) -> Result<Polynomial, &'static str> {
+    if values.is_empty() {
+        return Err("there aren't any values");
+    }
+    let mut row: Vec<BigInt> = values.iter().map(|v| BigInt::from(*v)).collect();
+    let mut differences = vec![];
+    while row.len() > 1 {
+        differences.push(row[0].clone());
+        if row.iter().all(|v| *v == row[0]) {
+            return Ok(Polynomial { differences });
+        }
+        row = row.windows(2).map(|w| &w[1] - &w[0]).collect();
+    }
+    Err("the differences never settle down to all being the same")
+}
+
+// Adds up the values of each line's polynomial at [x], which depends on the line.
+fn extrapolate(lines: &[Vec<i64>], x: impl Fn(&[i64]) -> i64) -> Answer {
+    let mut total = BigInt::zero();
+    for line in lines {
+        match fit(line) {
+            Ok(polynomial) => total += polynomial.at(x(line)),
+            Err(e) => return Answer::Failed(e.to_string()),
+        }
+    }
+    total.into()
+}
+
+fn parse(s: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    s.trim()
+        .lines()
+        .map(|line| line.split_whitespace().map(parse::number::<i64>).collect())
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(s))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse(s)
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        extrapolate(lines, |line| line.len() as i64)
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        extrapolate(lines, |_| -1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn parses_example() {
+        let lines = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], vec![0, 3, 6, 9, 12, 15]);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input), Answer::Integer(114));
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input), Answer::Integer(2));
+    }
+
+    #[test]
+    fn fits_polynomials() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        let degrees: Vec<usize> = input.iter().map(|v| fit(v).unwrap().degree()).collect();
+        assert_eq!(degrees, vec![1, 2, 3]);
+
+        // The third line is (x^3 - 3x^2 + 11x + 30) / 3, which goes a long way both ways.
+        let cubic = fit(&input[2]).unwrap();
+        for x in [-1_000_000, -7, -1, 0, 5, 6, 1_000_000_000] {
+            let big = BigInt::from(x);
+            let expected = (big.pow(3) - 3 * big.pow(2) + 11 * &big + 30) / 3;
+            assert_eq!(cubic.at(x), expected, "at {x}");
+        }
+
+        let constant = fit(&[4, 4, 4]).unwrap();
+        assert_eq!(constant.degree(), 0);
+        assert_eq!(constant.at(-3), BigInt::from(4));
+    }
+
+    #[test]
+    fn only_fits_polynomials() {
+        assert_eq!(fit(&[]), Err("there aren't any values"));
+        // Powers of two never have constant differences. Two different values, or just one, could
+        // be the start of anything.
+        for values in [&[1, 2, 4, 8, 16][..], &[3, 5], &[7]] {
+            assert_eq!(
+                fit(values),
+                Err("the differences never settle down to all being the same")
+            );
+        }
+        let input = Day9::parse("1 2 4 8 16\n").unwrap();
+        assert_eq!(
+            Day9::part1(&input),
+            Answer::Failed("the differences never settle down to all being the same".to_string())
+        );
+    }
+
+    #[test]
+    #[ignore = "needs inputs/day09.txt and answers/day09.toml"]
+    fn real_answers() {
+        aoc_common::answers::check::<Day9>(9);
+    }
+}